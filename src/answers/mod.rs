@@ -0,0 +1,147 @@
+use std::fs;
+
+use crate::{
+    json::Value,
+    params::Overrides,
+    solution::{Solution, DEFAULT_YEAR},
+    visualize::Frames,
+    Answer,
+};
+
+/// Default path of the answers manifest, relative to the repository root.
+pub const MANIFEST: &str = "answers.json";
+
+/// Recorded answers of a day for one of its inputs.
+#[derive(Debug, Clone)]
+pub struct Expected {
+    pub year: u32,
+    pub day: u32,
+    pub input: String,
+    pub parts: [Option<Answer>; 2],
+}
+
+/// Loads the answers manifest at `path`, a JSON array of objects such as
+/// `{"year": 2022, "day": 1, "input": "input/2022/day01", "part1": 72070, "part2": 211805}`,
+/// the year defaulting to [`DEFAULT_YEAR`].
+pub fn load(path: &str) -> crate::Result<Vec<Expected>> {
+    let invalid = |msg| crate::Error::data(path, msg);
+
+    let manifest: Value = fs::read_to_string(path)
+        .map_err(|err| crate::Error::io(path, err))?
+        .parse()
+        .map_err(|err| crate::Error::json(path, err))?;
+    let mut expected = Vec::new();
+    for entry in manifest
+        .as_array()
+        .ok_or_else(|| invalid("expected an array"))?
+    {
+        let year = match entry.get("year") {
+            Some(year) => year
+                .as_i64()
+                .and_then(|year| u32::try_from(year).ok())
+                .ok_or_else(|| invalid("expected a 'year' number"))?,
+            None => DEFAULT_YEAR,
+        };
+        let day = entry
+            .get("day")
+            .and_then(Value::as_i64)
+            .and_then(|day| u32::try_from(day).ok())
+            .ok_or_else(|| invalid("expected a 'day' number"))?;
+        let input = entry
+            .get("input")
+            .and_then(Value::as_str)
+            .ok_or_else(|| invalid("expected an 'input' path"))?
+            .to_string();
+
+        let mut parts = [None, None];
+        for (idx, key) in ["part1", "part2"].into_iter().enumerate() {
+            parts[idx] = match entry.get(key) {
+                Some(Value::Int(n)) => Some(Answer::Int(*n)),
+                Some(Value::String(s)) => Some(Answer::Text(s.clone())),
+                Some(_) => return Err(invalid("expected answers to be numbers or strings")),
+                None => None,
+            };
+        }
+
+        expected.push(Expected {
+            year,
+            day,
+            input,
+            parts,
+        });
+    }
+    Ok(expected)
+}
+
+/// Runs `part` of `solution` on `input` with `params`, `None` if the day has no such part.
+pub fn solve(
+    solution: &dyn Solution,
+    input: &str,
+    params: &Overrides,
+    part: u32,
+) -> Option<crate::Result<Answer>> {
+    // only trim the end, leading whitespace is significant for some days (e.g. day22)
+    let input = input.trim_end();
+    if part == 1 {
+        Some(solution.part1(input, params))
+    } else {
+        solution.part2(input, params)
+    }
+}
+
+/// Runs `part` of `solution` on `input` like [`solve`], drawing its frames to `frames`. `None` if
+/// the part is not visualized.
+pub fn visualize(
+    solution: &dyn Solution,
+    input: &str,
+    params: &Overrides,
+    part: u32,
+    frames: &mut Frames,
+) -> Option<crate::Result<Answer>> {
+    solution.visualize(input.trim_end(), params, part, frames)
+}
+
+/// Whether `actual` matches `expected`, compared by how they are displayed so that numbers
+/// recorded as strings still match. Trailing whitespace is ignored.
+pub fn matches(actual: &Answer, expected: &Answer) -> bool {
+    actual.to_string().trim_end() == expected.to_string().trim_end()
+}
+
+/// Checks all recorded answers of `solution`'s day, panicking on the first mismatch.
+#[cfg(test)]
+pub fn assert_answers(solution: &dyn Solution) {
+    let root = env!("CARGO_MANIFEST_DIR");
+    let expected = load(&format!("{}/{}", root, MANIFEST)).unwrap();
+
+    let mut checked = 0;
+    for expected in expected
+        .iter()
+        .filter(|e| e.year == solution.year() && e.day == solution.day())
+    {
+        let input = fs::read_to_string(format!("{}/{}", root, expected.input)).unwrap();
+        for (idx, answer) in expected.parts.iter().enumerate() {
+            if let Some(answer) = answer {
+                let part = idx as u32 + 1;
+                let actual = solve(solution, &input, &Overrides::default(), part)
+                    .expect("no such part")
+                    .unwrap();
+                assert!(
+                    matches(&actual, answer),
+                    "day {} part {} on '{}': expected {}, got {}",
+                    expected.day,
+                    part,
+                    expected.input,
+                    answer,
+                    actual
+                );
+                checked += 1;
+            }
+        }
+    }
+    assert!(
+        checked > 0,
+        "no answers recorded for {} day {}",
+        solution.year(),
+        solution.day()
+    );
+}
@@ -0,0 +1,104 @@
+use std::{fs, io, path::Path, time::Duration};
+
+use super::Stats;
+use aoc::{
+    json::{nanos, Value},
+    solution::DEFAULT_YEAR,
+    Error,
+};
+
+fn invalid(path: &str) -> Error {
+    Error::data(path, "expected an array of benchmark results")
+}
+
+fn duration(path: &str, value: Option<&Value>) -> aoc::Result<Duration> {
+    value
+        .and_then(Value::as_i64)
+        .and_then(|n| u64::try_from(n).ok())
+        .map(Duration::from_nanos)
+        .ok_or_else(|| invalid(path))
+}
+
+/// Benchmark results of previous runs, by year, day and part.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    entries: Vec<((u32, u32, u32), Stats)>,
+}
+
+impl Baseline {
+    /// Loads the baseline at `path`, which is empty if the file does not exist yet.
+    pub fn load(path: &str) -> aoc::Result<Self> {
+        let s = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(err) => return Err(Error::io(path, err)),
+        };
+
+        let value: Value = s.parse().map_err(|err| Error::json(path, err))?;
+        let mut entries = Vec::new();
+        for entry in value.as_array().ok_or_else(|| invalid(path))? {
+            let num = |key| {
+                entry
+                    .get(key)
+                    .and_then(Value::as_i64)
+                    .and_then(|n| u32::try_from(n).ok())
+                    .ok_or_else(|| invalid(path))
+            };
+            let stats = Stats {
+                runs: num("runs")? as usize,
+                min: duration(path, entry.get("min"))?,
+                median: duration(path, entry.get("median"))?,
+                mean: duration(path, entry.get("mean"))?,
+                stddev: duration(path, entry.get("stddev"))?,
+            };
+            // baselines from before there were other years have no year
+            let year = match entry.get("year") {
+                Some(_) => num("year")?,
+                None => DEFAULT_YEAR,
+            };
+            entries.push(((year, num("day")?, num("part")?), stats));
+        }
+
+        Ok(Baseline { entries })
+    }
+
+    pub fn save(&self, path: &str) -> aoc::Result<()> {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir).map_err(|err| Error::io(dir.to_string_lossy(), err))?;
+        }
+
+        let entries = self
+            .entries
+            .iter()
+            .map(|((year, day, part), stats)| {
+                Value::Object(vec![
+                    ("year".to_string(), (*year).into()),
+                    ("day".to_string(), (*day).into()),
+                    ("part".to_string(), (*part).into()),
+                    ("runs".to_string(), (stats.runs as i64).into()),
+                    ("min".to_string(), nanos(stats.min)),
+                    ("median".to_string(), nanos(stats.median)),
+                    ("mean".to_string(), nanos(stats.mean)),
+                    ("stddev".to_string(), nanos(stats.stddev)),
+                ])
+            })
+            .collect();
+        fs::write(path, format!("{:#}\n", Value::Array(entries)))
+            .map_err(|err| Error::io(path, err))?;
+        Ok(())
+    }
+
+    /// The results for `key`, a year, day and part.
+    pub fn get(&self, key: (u32, u32, u32)) -> Option<&Stats> {
+        self.entries
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, stats)| stats)
+    }
+
+    pub fn insert(&mut self, key: (u32, u32, u32), stats: Stats) {
+        self.entries.retain(|(k, _)| *k != key);
+        self.entries.push((key, stats));
+        self.entries.sort_by_key(|(k, _)| *k);
+    }
+}
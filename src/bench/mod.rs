@@ -0,0 +1,197 @@
+mod baseline;
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{format_time, time, Options};
+use aoc::Solution;
+use baseline::Baseline;
+
+/// Summary of the durations of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+
+        // sample standard deviation
+        let stddev = if runs > 1 {
+            let variance = samples
+                .iter()
+                .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (runs - 1) as f64;
+            Duration::from_secs_f64(variance.sqrt())
+        } else {
+            Duration::ZERO
+        };
+
+        Stats {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+            stddev,
+        }
+    }
+}
+
+/// Repeatedly runs `f` as configured by `opts`, `None` if `f` has nothing to run.
+fn measure<T, F>(f: F, opts: &Options) -> aoc::Result<Option<Stats>>
+where
+    F: Fn() -> Option<aoc::Result<T>>,
+{
+    for _ in 0..opts.warmup {
+        match f() {
+            Some(res) => _ = black_box(res?),
+            None => return Ok(None),
+        }
+    }
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty()
+        || match opts.budget {
+            Some(budget) => start.elapsed() < budget,
+            None => samples.len() < opts.runs,
+        }
+    {
+        match time(|()| f(), ()) {
+            (Some(res), d) => {
+                _ = black_box(res?);
+                samples.push(d);
+            }
+            (None, _) => return Ok(None),
+        }
+    }
+
+    Ok(Some(Stats::from_samples(&mut samples)))
+}
+
+fn print_row(name: &str, stats: &Stats, solve: Option<Duration>) {
+    println!(
+        "{:<6}  {:>5}  {:>13}  {:>13}  {:>13}  {:>13}  {:>13}",
+        name,
+        stats.runs,
+        format_time(stats.min),
+        format_time(stats.median),
+        format_time(stats.mean),
+        format_time(stats.stddev),
+        solve.map(format_time).unwrap_or_default(),
+    );
+}
+
+/// Prints how `stats` compare to `base`, flagging medians that got slower by more than the
+/// threshold.
+fn print_comparison(stats: &Stats, base: &Stats, opts: &Options) {
+    print_row("base", base, None);
+
+    let change = 100.0 * (stats.median.as_secs_f64() / base.median.as_secs_f64() - 1.0);
+    if change > opts.threshold {
+        println!(
+            "change  {:+.1}% median, REGRESSION above {}% threshold",
+            change, opts.threshold
+        );
+    } else {
+        println!("change  {:+.1}% median", change);
+    }
+}
+
+/// Benchmarks the parts of `solution` selected by `opts`, along with its parsing step.
+///
+/// As most days parse their input in each part, the `solve` column is the median of the part
+/// without the median parsing time. Parts are compared against the baseline in `opts`, and saved
+/// to it if requested.
+pub fn bench(
+    solution: &dyn Solution,
+    input: &str,
+    opts: &Options,
+) -> aoc::Result<[Option<Stats>; 2]> {
+    // only trim the end, leading whitespace is significant for some days (e.g. day22)
+    let input = input.trim_end();
+
+    println!("Day {}: {}", solution.day(), solution.name());
+    match opts.budget {
+        Some(budget) => println!(
+            "{} warmup runs, {} seconds per step",
+            opts.warmup,
+            format_time(budget)
+        ),
+        None => println!("{} warmup runs, {} runs per step", opts.warmup, opts.runs),
+    }
+    println!(
+        "{:<6}  {:>5}  {:>13}  {:>13}  {:>13}  {:>13}  {:>13}",
+        "", "runs", "min", "median", "mean", "stddev", "solve"
+    );
+
+    let parse = measure(|| solution.parse(input), opts)?;
+    if let Some(stats) = &parse {
+        print_row("parse", stats, None);
+    }
+
+    let mut baseline = Baseline::load(&opts.baseline)?;
+    let report = |part, stats: &Option<Stats>| {
+        if let Some(stats) = stats {
+            let solve = parse.map(|parse| stats.median.saturating_sub(parse.median));
+            print_row(&format!("part {}", part), stats, solve);
+            if let Some(base) = baseline.get((solution.year(), solution.day(), part)) {
+                print_comparison(stats, base, opts);
+            }
+        }
+    };
+
+    let mut stats = [None; 2];
+    if opts.part != Some(2) {
+        stats[0] = measure(|| Some(solution.part1(input, &opts.params)), opts)?;
+        report(1, &stats[0]);
+    }
+    if opts.part != Some(1) {
+        stats[1] = measure(|| solution.part2(input, &opts.params), opts)?;
+        report(2, &stats[1]);
+    }
+
+    if opts.save_baseline {
+        for (idx, stats) in stats.iter().enumerate() {
+            if let Some(stats) = stats {
+                baseline.insert((solution.year(), solution.day(), idx as u32 + 1), *stats);
+            }
+        }
+        baseline.save(&opts.baseline)?;
+        println!("Saved baseline to '{}'", opts.baseline);
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1290);
+    }
+}
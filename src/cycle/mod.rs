@@ -0,0 +1,95 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A simulation that eventually repeats itself, recorded up to its first repetition.
+#[derive(Debug, Clone)]
+pub struct Cycle<R> {
+    /// The number of steps before the repeating part.
+    pub start: usize,
+    /// The number of steps after which the state repeats.
+    pub len: usize,
+    /// What was recorded for the initial state and after each step up to `start + len`.
+    records: Vec<R>,
+}
+
+impl<R> Cycle<R> {
+    /// Runs `step` on `state` until the key of a state has been seen before, using `record` to
+    /// keep what is needed of each state. This never returns if the keys never repeat.
+    pub fn find<S, K>(
+        mut state: S,
+        mut step: impl FnMut(&mut S),
+        mut key: impl FnMut(&S) -> K,
+        mut record: impl FnMut(&S) -> R,
+    ) -> Self
+    where
+        K: Eq + Hash,
+    {
+        let mut seen = HashMap::new();
+        let mut records = Vec::new();
+        for n in 0.. {
+            records.push(record(&state));
+            if let Some(start) = seen.insert(key(&state), n) {
+                return Cycle {
+                    start,
+                    len: n - start,
+                    records,
+                };
+            }
+            step(&mut state);
+        }
+        unreachable!()
+    }
+
+    /// The number of steps up to the end of the first cycle after which the state is the same as
+    /// after `n` steps.
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+
+    /// What was recorded for the state after `n` steps.
+    pub fn at(&self, n: usize) -> &R {
+        &self.records[self.equivalent(n)]
+    }
+
+    /// The value of a metric after `n` steps, such as a height that keeps growing, assuming it
+    /// changes by the same amount on each cycle. `metric` computes it from a record.
+    pub fn fast_forward(&self, n: usize, metric: impl Fn(&R) -> i64) -> i64 {
+        if n < self.start {
+            return metric(&self.records[n]);
+        }
+
+        let cycles = ((n - self.start) / self.len) as i64;
+        let per_cycle =
+            metric(&self.records[self.start + self.len]) - metric(&self.records[self.start]);
+        metric(self.at(n)) + cycles * per_cycle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycle() {
+        // powers of 2 modulo 11 repeat after 10 steps, adding up to 55 on each cycle
+        let cycle = Cycle::find(
+            (1, 0),
+            |(n, sum)| (*n, *sum) = (*n * 2 % 11, *sum + *n),
+            |(n, _)| *n,
+            |(_, sum)| *sum,
+        );
+        assert_eq!((cycle.start, cycle.len), (0, 10));
+        assert_eq!(cycle.equivalent(23), 3);
+        assert_eq!(*cycle.at(3), 7);
+        assert_eq!(cycle.fast_forward(3, |sum| *sum), 7);
+        assert_eq!(cycle.fast_forward(23, |sum| *sum), 2 * 55 + 7);
+
+        let cycle = Cycle::find(0, |n| *n = (*n + 1).min(3), |n| *n, |n| *n);
+        assert_eq!((cycle.start, cycle.len), (3, 1));
+        assert_eq!(cycle.fast_forward(1, |n| *n), 1);
+        assert_eq!(*cycle.at(1000), 3);
+    }
+}
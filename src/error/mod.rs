@@ -0,0 +1,116 @@
+use std::{error, fmt, io, result};
+
+use crate::{json, parse};
+
+pub type Result<T> = result::Result<T, Error>;
+
+/// Everything that can go wrong, in the solvers as well as in the runner around them.
+#[derive(Debug)]
+pub enum Error {
+    /// Invalid command line arguments.
+    Usage(String),
+    /// Reading or writing a file failed, `-` being stdin.
+    Io { path: String, source: io::Error },
+    /// Malformed puzzle input.
+    Parse(parse::Error),
+    /// A file of the runner, such as the answers manifest or a baseline, is not valid JSON.
+    Json { path: String, source: json::Error },
+    /// A file of the runner is valid JSON, but not what was expected.
+    Data { path: String, msg: String },
+    /// A solver failed on input that parsed fine, e.g. because there is no path to the goal.
+    Solve { day: u32, msg: String },
+    /// Verification found this many wrong or missing answers.
+    Verify(usize),
+}
+
+impl Error {
+    pub fn io(path: impl Into<String>, source: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub fn json(path: impl Into<String>, source: json::Error) -> Self {
+        Error::Json {
+            path: path.into(),
+            source,
+        }
+    }
+
+    pub fn data(path: impl Into<String>, msg: impl Into<String>) -> Self {
+        Error::Data {
+            path: path.into(),
+            msg: msg.into(),
+        }
+    }
+
+    pub fn solve(day: u32, msg: impl Into<String>) -> Self {
+        Error::Solve {
+            day,
+            msg: msg.into(),
+        }
+    }
+
+    /// Exit code of the binary when failing with this error.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::Verify(_) => 1,
+            Error::Usage(_) => 2,
+            Error::Io { .. } => 3,
+            Error::Parse(_) => 4,
+            Error::Solve { .. } => 5,
+            Error::Json { .. } | Error::Data { .. } => 6,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(msg) => write!(f, "{}", msg),
+            Error::Io { path, .. } if path == "-" => write!(f, "could not read stdin"),
+            Error::Io { path, .. } => write!(f, "could not access '{}'", path),
+            Error::Parse(err) => write!(f, "{}", err),
+            Error::Json { path, .. } => write!(f, "could not parse '{}'", path),
+            Error::Data { path, msg } => write!(f, "invalid '{}': {}", path, msg),
+            Error::Solve { day, msg } => write!(f, "day{:02}: {}", day, msg),
+            Error::Verify(failed) => write!(f, "{} answers failed verification", failed),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Json { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<parse::Error> for Error {
+    fn from(err: parse::Error) -> Self {
+        Error::Parse(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn chain() {
+        let err = Error::io("input/2022/day01", io::Error::from(io::ErrorKind::NotFound));
+        assert_eq!(err.to_string(), "could not access 'input/2022/day01'");
+        assert_eq!(err.source().unwrap().to_string(), "entity not found");
+        assert!(err.source().unwrap().source().is_none());
+
+        let err = Error::solve(12, "no path found");
+        assert_eq!(err.to_string(), "day12: no path found");
+        assert!(err.source().is_none());
+        assert_ne!(err.exit_code(), Error::Usage(String::new()).exit_code());
+    }
+}
@@ -0,0 +1,170 @@
+use std::{fs, io};
+
+use crate::{params::Overrides, Answer};
+
+/// Directory of the examples, relative to the repository root.
+pub const DIR: &str = "examples";
+
+/// A worked example from a puzzle's description, along with the answers it gives.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub path: String,
+    pub input: String,
+    /// The parameters the example differs from the actual puzzle in.
+    pub params: Overrides,
+    pub parts: [Option<Answer>; 2],
+}
+
+impl Example {
+    /// Parses an example file: the puzzle input, followed by the answer to each part the example
+    /// has one for, on the lines after a `--- part 1` or `--- part 2` line. Parameters the example
+    /// needs are given as `name=value`, one per line after a `--- params` line.
+    pub fn parse(path: &str, text: &str) -> crate::Result<Self> {
+        let mut input = Vec::new();
+        let mut answers: Vec<(&str, Vec<&str>)> = Vec::new();
+        for line in text.lines() {
+            if let Some(header) = line.strip_prefix("--- ") {
+                answers.push((header, Vec::new()));
+            } else if let Some((_, answer)) = answers.last_mut() {
+                answer.push(line);
+            } else {
+                input.push(line);
+            }
+        }
+
+        let mut params = Overrides::default();
+        let mut parts = [None, None];
+        for (header, answer) in answers {
+            let idx = match header.trim_end() {
+                "part 1" => 0,
+                "part 2" => 1,
+                "params" => {
+                    for pair in answer.iter().filter(|pair| !pair.trim().is_empty()) {
+                        params.insert(pair).ok_or_else(|| {
+                            let msg =
+                                format!("expected a parameter as name=value, found '{}'", pair);
+                            crate::Error::data(path, msg)
+                        })?;
+                    }
+                    continue;
+                }
+                _ => {
+                    let msg = format!(
+                        "expected '--- part 1', '--- part 2' or '--- params', found '--- {}'",
+                        header
+                    );
+                    return Err(crate::Error::data(path, msg));
+                }
+            };
+            let answer = answer.join("\n");
+            let answer = match answer.trim_end().parse() {
+                Ok(n) => Answer::Int(n),
+                Err(_) => Answer::Text(answer),
+            };
+            if parts[idx].replace(answer).is_some() {
+                let msg = format!("part {} answered twice", idx + 1);
+                return Err(crate::Error::data(path, msg));
+            }
+        }
+
+        Ok(Example {
+            path: path.to_string(),
+            input: input.join("\n"),
+            params,
+            parts,
+        })
+    }
+}
+
+/// The paths of the examples of `day` of `year` in `dir`, which are the files
+/// `<year>/dayNN-*.txt` in name order. None if there is no directory for `year`.
+pub fn paths(dir: &str, year: u32, day: u32) -> crate::Result<Vec<String>> {
+    let dir = format!("{}/{}", dir, year);
+    let prefix = format!("day{:02}-", day);
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(crate::Error::io(dir, err)),
+    };
+    let mut paths = Vec::new();
+    for entry in entries {
+        let name = entry
+            .map_err(|err| crate::Error::io(&dir, err))?
+            .file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with(&prefix) && name.ends_with(".txt") {
+            paths.push(format!("{}/{}", dir, name));
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// Loads the examples of `day` of `year` from `dir`, in the order of [`paths`].
+pub fn load(dir: &str, year: u32, day: u32) -> crate::Result<Vec<Example>> {
+    paths(dir, year, day)?
+        .iter()
+        .map(|path| {
+            let text = fs::read_to_string(path).map_err(|err| crate::Error::io(path, err))?;
+            Example::parse(path, &text)
+        })
+        .collect()
+}
+
+/// Checks `solution` against the answers given by each of its examples, panicking on the first
+/// mismatch.
+#[cfg(test)]
+pub fn assert_examples(solution: &dyn crate::Solution) {
+    use crate::answers::{matches, solve};
+
+    let dir = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), DIR);
+    for example in load(&dir, solution.year(), solution.day()).unwrap() {
+        for (idx, answer) in example.parts.iter().enumerate() {
+            if let Some(answer) = answer {
+                let part = idx as u32 + 1;
+                let actual = solve(solution, &example.input, &example.params, part)
+                    .expect("no such part")
+                    .unwrap();
+                assert!(
+                    matches(&actual, answer),
+                    "day {} part {} on '{}': expected {}, got {}",
+                    solution.day(),
+                    part,
+                    example.path,
+                    answer,
+                    actual
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let text = "  a\nb\n\n--- part 1\n42\n--- part 2\n#.\n.#\n";
+        let example = Example::parse("ex", text).unwrap();
+        assert_eq!(example.input, "  a\nb\n");
+        assert_eq!(
+            example.parts,
+            [
+                Some(Answer::Int(42)),
+                Some(Answer::Text("#.\n.#".to_string()))
+            ]
+        );
+
+        let example = Example::parse("ex", "a\n--- params\ny=10\n--- part 2\nb").unwrap();
+        assert_eq!(example.params.iter().collect::<Vec<_>>(), [("y", "10")]);
+        assert_eq!(example.parts, [None, Some(Answer::Text("b".to_string()))]);
+
+        let err = Example::parse("ex", "a\n--- part 3\n1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid 'ex': expected '--- part 1', '--- part 2' or '--- params', found '--- part 3'"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Numbers that points can be made of, the signed integers.
+pub trait Coord:
+    Copy
+    + Ord
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + From<i8>
+{
+}
+
+impl<T> Coord for T where
+    T: Copy
+        + Ord
+        + Default
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Neg<Output = T>
+        + From<i8>
+{
+}
+
+fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn signum<T: Coord>(n: T) -> T {
+    T::from(match n.cmp(&T::default()) {
+        std::cmp::Ordering::Less => -1,
+        std::cmp::Ordering::Equal => 0,
+        std::cmp::Ordering::Greater => 1,
+    })
+}
+
+/// Points of any dimension, as a sequence of coordinates.
+pub trait Axes: Copy {
+    type Coord: Coord;
+
+    /// A point with all coordinates set to `n`.
+    fn splat(n: Self::Coord) -> Self;
+
+    /// Combines the coordinates of both points axis by axis.
+    fn zip(self, other: Self, f: impl Fn(Self::Coord, Self::Coord) -> Self::Coord) -> Self;
+
+    /// Whether `f` holds for the coordinates of both points along each axis.
+    fn all(self, other: Self, f: impl Fn(Self::Coord, Self::Coord) -> bool) -> bool;
+}
+
+/// Bounds of a set of points, including both corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds<P> {
+    pub min: P,
+    pub max: P,
+}
+
+impl<P: Axes> Bounds<P> {
+    /// The smallest bounds containing all of `points`, `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = P>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |bounds, p| Bounds {
+                min: bounds.min.zip(p, Ord::min),
+                max: bounds.max.zip(p, Ord::max),
+            },
+        ))
+    }
+
+    pub fn contains(&self, p: P) -> bool {
+        self.min.all(p, |min, n| min <= n) && self.max.all(p, |max, n| n <= max)
+    }
+
+    /// The bounds extended by `n` in every direction.
+    pub fn grow(self, n: P::Coord) -> Self {
+        Bounds {
+            min: self.min.zip(P::splat(n), |a, b| a - b),
+            max: self.max.zip(P::splat(n), |a, b| a + b),
+        }
+    }
+}
+
+macro_rules! point {
+    ($(#[$attr:meta])* $name:ident { $($axis:ident),+ }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<T> {
+            $(pub $axis: T),+
+        }
+
+        impl<T> $name<T> {
+            pub const fn new($($axis: T),+) -> Self {
+                $name { $($axis),+ }
+            }
+        }
+
+        impl<T: Coord> $name<T> {
+            /// Sum of the distances along each axis.
+            pub fn manhattan(self, other: Self) -> T {
+                T::default() $(+ abs_diff(self.$axis, other.$axis))+
+            }
+
+            /// Largest of the distances along each axis, i.e. the number of king's moves between
+            /// the points.
+            pub fn chebyshev(self, other: Self) -> T {
+                T::default() $(.max(abs_diff(self.$axis, other.$axis)))+
+            }
+
+            /// The sign of each coordinate, a step towards `self` from the origin.
+            pub fn signum(self) -> Self {
+                $name { $($axis: signum(self.$axis)),+ }
+            }
+        }
+
+        impl<T: Coord> Axes for $name<T> {
+            type Coord = T;
+
+            fn splat(n: T) -> Self {
+                $name { $($axis: n),+ }
+            }
+
+            fn zip(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+                $name { $($axis: f(self.$axis, other.$axis)),+ }
+            }
+
+            fn all(self, other: Self, f: impl Fn(T, T) -> bool) -> bool {
+                true $(&& f(self.$axis, other.$axis))+
+            }
+        }
+
+        impl<T: Coord> Add for $name<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                self.zip(other, |a, b| a + b)
+            }
+        }
+
+        impl<T: Coord> Sub for $name<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                self.zip(other, |a, b| a - b)
+            }
+        }
+
+        impl<T: Coord> AddAssign for $name<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coord> SubAssign for $name<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl<T: Coord> Mul<T> for $name<T> {
+            type Output = Self;
+
+            fn mul(self, n: T) -> Self {
+                $name { $($axis: self.$axis * n),+ }
+            }
+        }
+    };
+}
+
+point! {
+    /// A point in the plane, `y` growing downwards as in the puzzle inputs.
+    Point2 { x, y }
+}
+
+point! {
+    /// A point in space.
+    Point3 { x, y, z }
+}
+
+impl<T: Coord> Point2<T> {
+    /// The 4 points sharing an edge with `self`.
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        Dir::ALL.into_iter().map(move |dir| self + dir.delta())
+    }
+
+    /// The 8 points sharing an edge or a corner with `self`.
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        Dir::ALL
+            .into_iter()
+            .flat_map(move |dir| [self + dir.delta(), self + dir.delta() + dir.right().delta()])
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    /// The 6 points sharing a face with `self`.
+    pub fn neighbors6(self) -> impl Iterator<Item = Self> {
+        let (one, zero) = (T::from(1), T::default());
+        [
+            Point3::new(one, zero, zero),
+            Point3::new(zero, one, zero),
+            Point3::new(zero, zero, one),
+        ]
+        .into_iter()
+        .flat_map(move |d| [self - d, self + d])
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+impl<T> From<Point2<T>> for (T, T) {
+    fn from(p: Point2<T>) -> Self {
+        (p.x, p.y)
+    }
+}
+
+/// A turn by 90 degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// One of the 4 directions in the plane, clockwise from `Up`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// A step in this direction, `Up` decreasing `y`.
+    pub fn delta<T: Coord>(self) -> Point2<T> {
+        let (x, y) = match self {
+            Dir::Up => (0, -1),
+            Dir::Right => (1, 0),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+        };
+        Point2::new(T::from(x), T::from(y))
+    }
+
+    pub fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.left(),
+            Turn::Right => self.right(),
+        }
+    }
+
+    pub fn left(self) -> Self {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn right(self) -> Self {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geom() {
+        let (a, b) = (Point2::new(1, 2), Point2::new(4, -2));
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(b * 2, Point2::new(8, -4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((a - b).signum(), Point2::new(-1, 1));
+        assert_eq!(a.neighbors8().count(), 8);
+        assert!(a.neighbors8().all(|n| n.chebyshev(a) == 1));
+
+        assert_eq!(Dir::Up.turn(Turn::Left), Dir::Left);
+        assert_eq!(Dir::Left.right(), Dir::Up);
+        assert_eq!(Dir::Down.reverse(), Dir::Up);
+        assert_eq!(a + Dir::Up.delta() * 3, Point2::new(1, -1));
+
+        let points = [Point3::new(1, 5, 3), Point3::new(2, 2, 2)];
+        let bounds = Bounds::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point3::new(1, 2, 2));
+        assert_eq!(bounds.max, Point3::new(2, 5, 3));
+        assert!(bounds.contains(Point3::new(2, 3, 3)));
+        assert!(!bounds.contains(Point3::new(0, 3, 3)));
+        assert!(bounds.grow(1).contains(Point3::new(0, 3, 3)));
+        assert_eq!(Point3::new(0, 0, 0).neighbors6().count(), 6);
+    }
+}
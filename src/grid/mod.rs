@@ -0,0 +1,258 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::{self, Parser};
+
+/// Position of a cell as `(x, y)`, signed so that positions off the grid can be represented.
+pub type Pos = (i32, i32);
+
+/// A rectangular grid of cells stored row by row, `(0, 0)` being the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Builds a grid from rows of possibly different lengths, padding short rows with `fill`.
+    pub fn from_rows<I>(rows: I, fill: T) -> Self
+    where
+        T: Clone,
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        let rows: Vec<_> = rows.into_iter().collect();
+        let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character of `s`, which must be rectangular. `cell` returns `None` for
+    /// characters that are not valid cells.
+    pub fn parse<F>(p: &Parser, s: &str, mut cell: F) -> Result<Self, parse::Error>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in s.lines() {
+            let len = cells.len();
+            for (idx, c) in line.char_indices() {
+                let at = &line[idx..];
+                cells.push(cell(c).ok_or_else(|| p.error(at, format!("unexpected '{}'", c)))?);
+            }
+
+            let w = *width.get_or_insert(cells.len() - len);
+            if cells.len() - len != w {
+                return Err(p.error(line, format!("expected {} columns", w)));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn idx(&self, pos: Pos) -> Option<usize> {
+        if self.contains(pos) {
+            Some(pos.1 as usize * self.width + pos.0 as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.idx(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.idx(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// Replaces the cell at `pos`, returning the previous one, `None` if `pos` is off the grid.
+    pub fn set(&mut self, pos: Pos, cell: T) -> Option<T> {
+        self.get_mut(pos).map(|old| std::mem::replace(old, cell))
+    }
+
+    /// Grows or shrinks the grid to `height` rows, filling new rows with `cell`.
+    pub fn resize(&mut self, height: usize, cell: T)
+    where
+        T: Clone,
+    {
+        self.cells.resize(self.width * height, cell);
+        self.height = height;
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width as i32, self.height as i32);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells along with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The up to 4 positions on the grid sharing an edge with `pos`.
+    pub fn neighbors4(&self, (x, y): Pos) -> impl Iterator<Item = Pos> + '_ {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|pos| self.contains(*pos))
+    }
+
+    /// The up to 8 positions on the grid sharing an edge or a corner with `pos`.
+    pub fn neighbors8(&self, (x, y): Pos) -> impl Iterator<Item = Pos> + '_ {
+        (-1..=1)
+            .flat_map(move |dy| (-1..=1).map(move |dx| (x + dx, y + dy)))
+            .filter(move |pos| *pos != (x, y) && self.contains(*pos))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// A grid of the same size with `f` applied to each cell.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Position of the first cell, row by row, for which `f` holds.
+    pub fn position<F: FnMut(&T) -> bool>(&self, mut f: F) -> Option<Pos> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    /// Mirrors the grid along its diagonal, turning rows into columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: self.columns().flatten().cloned().collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).expect("position off the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).expect("position off the grid")
+    }
+}
+
+/// Renders one line per row, without separators between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid() {
+        let input = "123\n456";
+        let p = Parser::new(0, input);
+        let mut grid = Grid::parse(&p, input, |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&6));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.set((0, 0), 0), Some(1));
+        assert_eq!(grid.set((0, -1), 0), None);
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 0)).count(), 5);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.transpose().to_string(), "04\n25\n36");
+
+        let input = "12\n3x";
+        let err = Grid::parse(&Parser::new(0, input), input, |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            (err.line, err.col, err.msg.as_str()),
+            (2, 2, "unexpected 'x'")
+        );
+
+        let grid = Grid::from_rows([vec!['a'], vec!['b', 'c']], ' ');
+        assert_eq!(grid.to_string(), "a \nbc");
+    }
+}
@@ -0,0 +1,325 @@
+use std::{error, fmt, str::FromStr, time::Duration};
+
+/// Invalid JSON, at a byte offset into the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error(pub usize);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid JSON at byte {}", self.0)
+    }
+}
+
+impl error::Error for Error {}
+
+/// A JSON value, limited to what the runner reads and writes: numbers are integers only.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Self {
+        Value::Int(n)
+    }
+}
+
+impl From<u32> for Value {
+    fn from(n: u32) -> Self {
+        Value::Int(n.into())
+    }
+}
+
+/// Durations are stored as integer nanoseconds.
+pub fn nanos(d: Duration) -> Value {
+    Value::Int(i64::try_from(d.as_nanos()).unwrap_or(i64::MAX))
+}
+
+fn write_str(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+impl Value {
+    fn write(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        // `{:#}` puts each array element and object entry on its own line
+        let pretty = f.alternate();
+        let newline = |f: &mut fmt::Formatter<'_>, indent: usize| {
+            if pretty {
+                write!(f, "\n{:1$}", "", indent * 2)
+            } else {
+                Ok(())
+            }
+        };
+
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Int(n) => write!(f, "{}", n),
+            Value::String(s) => write_str(f, s),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    newline(f, indent + 1)?;
+                    value.write(f, indent + 1)?;
+                }
+                if !values.is_empty() {
+                    newline(f, indent)?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(entries) => {
+                write!(f, "{{")?;
+                for (idx, (key, value)) in entries.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ",")?;
+                    }
+                    newline(f, indent + 1)?;
+                    write_str(f, key)?;
+                    write!(f, ":")?;
+                    if pretty {
+                        write!(f, " ")?;
+                    }
+                    value.write(f, indent + 1)?;
+                }
+                if !entries.is_empty() {
+                    newline(f, indent)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    idx: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn err<T>(&self) -> Result<T, Error> {
+        Err(Error(self.idx))
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.idx += self.s[self.idx..]
+            .chars()
+            .take_while(|c| c.is_ascii_whitespace())
+            .count();
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.s[self.idx..].chars().next()
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), Error> {
+        self.skip_whitespace();
+        if self.s[self.idx..].starts_with(token) {
+            self.idx += token.len();
+            Ok(())
+        } else {
+            self.err()
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.expect("\"")?;
+        let mut s = String::new();
+        let mut chars = self.s[self.idx..].char_indices();
+        while let Some((offset, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.idx += offset + 1;
+                    return Ok(s);
+                }
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some('"') => s.push('"'),
+                    Some('\\') => s.push('\\'),
+                    Some('/') => s.push('/'),
+                    Some('n') => s.push('\n'),
+                    Some('r') => s.push('\r'),
+                    Some('t') => s.push('\t'),
+                    Some('u') => {
+                        let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                        match u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32) {
+                            Some(c) => s.push(c),
+                            None => return self.err(),
+                        }
+                    }
+                    _ => return self.err(),
+                },
+                c => s.push(c),
+            }
+        }
+        self.err()
+    }
+
+    fn value(&mut self) -> Result<Value, Error> {
+        match self.peek() {
+            Some('n') => self.expect("null").map(|_| Value::Null),
+            Some('t') => self.expect("true").map(|_| Value::Bool(true)),
+            Some('f') => self.expect("false").map(|_| Value::Bool(false)),
+            Some('"') => self.string().map(Value::String),
+            Some('[') => {
+                self.expect("[")?;
+                let mut values = Vec::new();
+                if self.peek() == Some(']') {
+                    self.idx += 1;
+                    return Ok(Value::Array(values));
+                }
+                loop {
+                    values.push(self.value()?);
+                    match self.peek() {
+                        Some(',') => self.idx += 1,
+                        Some(']') => {
+                            self.idx += 1;
+                            return Ok(Value::Array(values));
+                        }
+                        _ => return self.err(),
+                    }
+                }
+            }
+            Some('{') => {
+                self.expect("{")?;
+                let mut entries = Vec::new();
+                if self.peek() == Some('}') {
+                    self.idx += 1;
+                    return Ok(Value::Object(entries));
+                }
+                loop {
+                    let key = self.string()?;
+                    self.expect(":")?;
+                    entries.push((key, self.value()?));
+                    match self.peek() {
+                        Some(',') => self.idx += 1,
+                        Some('}') => {
+                            self.idx += 1;
+                            return Ok(Value::Object(entries));
+                        }
+                        _ => return self.err(),
+                    }
+                }
+            }
+            Some(c) if c == '-' || c.is_ascii_digit() => {
+                let len = self.s[self.idx + 1..]
+                    .chars()
+                    .take_while(char::is_ascii_digit)
+                    .count();
+                let n = match self.s[self.idx..=self.idx + len].parse() {
+                    Ok(n) => n,
+                    Err(_) => return self.err(),
+                };
+                self.idx += len + 1;
+                Ok(Value::Int(n))
+            }
+            _ => self.err(),
+        }
+    }
+}
+
+impl FromStr for Value {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { s, idx: 0 };
+        let value = parser.value()?;
+        if parser.peek().is_some() {
+            return parser.err();
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let value = Value::Object(vec![
+            ("day".to_string(), Value::Int(10)),
+            ("answer".to_string(), Value::from("#..#\n\"quoted\"")),
+            (
+                "list".to_string(),
+                Value::Array(vec![Value::Null, Value::Bool(true), Value::Int(-3)]),
+            ),
+            ("empty".to_string(), Value::Object(Vec::new())),
+        ]);
+
+        assert_eq!(value.to_string().parse::<Value>().unwrap(), value);
+        assert_eq!(format!("{:#}", value).parse::<Value>().unwrap(), value);
+        assert_eq!(
+            value.to_string(),
+            r##"{"day":10,"answer":"#..#\n\"quoted\"","list":[null,true,-3],"empty":{}}"##
+        );
+        assert!("[1, 2".parse::<Value>().is_err());
+    }
+}
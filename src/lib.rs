@@ -0,0 +1,117 @@
+//! Solutions to [Advent of Code](https://adventofcode.com/), one module per year.
+//!
+//! Each day is a module with `part1`/`part2` functions taking the puzzle input, and is registered
+//! as a [`Solution`] so it can be looked up with [`solution::get`] or iterated over with
+//! [`solution::all`].
+
+pub mod answers;
+pub mod cycle;
+mod error;
+pub mod examples;
+pub mod geom;
+pub mod grid;
+pub mod json;
+pub mod params;
+pub mod parse;
+pub mod random;
+pub mod search;
+pub mod solution;
+pub mod visualize;
+
+pub use error::{Error, Result};
+pub use solution::{Answer, Solution};
+
+/// Declares the parameters of a day as a `Params` struct, each with the default used for the
+/// actual puzzle, which can be overridden with [`params::Overrides`].
+macro_rules! params {
+    ($($(#[$attr:meta])* $name:ident: $ty:ty = $default:expr),* $(,)?) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct Params {
+            $($(#[$attr])* pub $name: $ty),*
+        }
+
+        impl Default for Params {
+            fn default() -> Self {
+                Params {
+                    $($name: $default),*
+                }
+            }
+        }
+
+        impl Params {
+            /// The defaults with `overrides` applied.
+            pub fn new(overrides: &crate::params::Overrides) -> crate::Result<Self> {
+                let mut params = Params::default();
+                for (name, value) in overrides.iter() {
+                    match name {
+                        $(stringify!($name) => {
+                            params.$name = crate::params::parse(DAY, name, value)?;
+                        })*
+                        _ => return Err(crate::params::unknown(DAY, name)),
+                    }
+                }
+                Ok(params)
+            }
+
+            /// The name and value of each parameter.
+            pub fn values(&self) -> Vec<(&'static str, String)> {
+                vec![$((stringify!($name), self.$name.to_string())),*]
+            }
+        }
+    };
+}
+
+macro_rules! days {
+    ($($day:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $day;)*
+
+        pub(crate) static DAYS: &[&dyn crate::Solution] = &[$(&$day::$solution),*];
+
+        #[cfg(test)]
+        mod tests {
+            /// Checks each day against its recorded answers.
+            mod answers {
+                $(
+                    #[test]
+                    fn $day() {
+                        crate::answers::assert_answers(&super::super::$day::$solution);
+                    }
+                )*
+            }
+
+            /// Checks each day against its examples.
+            mod examples {
+                $(
+                    #[test]
+                    fn $day() {
+                        crate::examples::assert_examples(&super::super::$day::$solution);
+                    }
+                )*
+            }
+
+            /// Checks that each day solves the inputs it generates.
+            mod generated {
+                $(
+                    #[test]
+                    fn $day() {
+                        crate::random::assert_generated(&super::super::$day::$solution);
+                    }
+                )*
+            }
+        }
+    };
+}
+
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(pub mod $year;)*
+
+        static YEARS: &[(u32, &[&dyn Solution])] = &[$(($year::YEAR, $year::DAYS)),*];
+    };
+}
+
+// each year is a module `src/yYYYY/mod.rs` listing its days, `aoc <year> new <day>` sets up a
+// day along with its year
+years! {
+    y2022,
+}
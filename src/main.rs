@@ -1,517 +1,981 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-
-use std::{env, error, fmt, fs, result, time};
-
-#[derive(Debug)]
-struct UsageError;
+mod bench;
+mod pool;
+mod scaffold;
+mod watch;
+
+use aoc::{
+    answers,
+    examples::{self, Example},
+    json,
+    params::Overrides,
+    random::Rng,
+    solution,
+    visualize::{Ascii, Frames, Gif, Ppm, Sink},
+    Answer, Error, Result, Solution,
+};
+use std::{
+    env, error, fs,
+    io::{self, IsTerminal, Read},
+    process::ExitCode,
+    result, time,
+};
+
+fn format_time(d: time::Duration) -> String {
+    format!(
+        "{}.{:03} {:03} {:03}",
+        d.as_secs(),
+        d.subsec_millis(),
+        d.subsec_micros() % 1_000,
+        d.subsec_nanos() % 1_000,
+    )
+}
 
-type Result<T> = result::Result<T, Box<dyn error::Error>>;
+fn write_time(out: &mut String, d: time::Duration) {
+    out.push_str(&format!("> {} seconds\n", format_time(d)));
+}
 
-#[derive(Debug)]
-struct Error<T> {
-    err: T,
+fn time<F: Fn(A) -> B, A, B>(f: F, a: A) -> (B, time::Duration) {
+    let now = time::Instant::now();
+    let res = f(a);
+    (res, now.elapsed())
 }
 
-impl<T> Error<T> {
-    fn boxed(err: T) -> Box<Self> {
-        Box::new(Self { err })
+fn write_answer(out: &mut String, part: u32, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        out.push_str(&format!("Part {}: \n{}\n", part, answer));
+    } else {
+        out.push_str(&format!("Part {}: {}\n", part, answer));
     }
 }
 
-impl<T: fmt::Debug> fmt::Display for Error<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.err)
+fn answer_value(answer: &Answer) -> json::Value {
+    match answer {
+        Answer::Int(n) => json::Value::Int(*n),
+        Answer::Text(s) => s.as_str().into(),
     }
 }
 
-impl<T: fmt::Debug> error::Error for Error<T> {
-    fn cause(&self) -> Option<&dyn error::Error> {
-        Some(self)
-    }
+/// The JSON record of the outcome of a part, or of a whole day if it did not run at all.
+fn record(
+    solution: &dyn Solution,
+    part: Option<u32>,
+    input: &str,
+    outcome: result::Result<(&Answer, time::Duration), String>,
+) -> json::Value {
+    let (answer, elapsed, error) = match outcome {
+        Ok((answer, d)) => (answer_value(answer), json::nanos(d), json::Value::Null),
+        Err(err) => (json::Value::Null, json::Value::Null, err.into()),
+    };
+
+    json::Value::Object(vec![
+        ("year".to_string(), solution.year().into()),
+        ("day".to_string(), solution.day().into()),
+        (
+            "part".to_string(),
+            part.map_or(json::Value::Null, Into::into),
+        ),
+        ("answer".to_string(), answer),
+        ("elapsed_ns".to_string(), elapsed),
+        ("input".to_string(), input.into()),
+        ("error".to_string(), error),
+    ])
 }
 
-fn print_time(d: time::Duration) {
-    println!(
-        "> {}.{:03} {:03} {:03} seconds",
-        d.as_secs(),
-        d.subsec_millis(),
-        d.subsec_micros() % 1_000,
-        d.subsec_nanos() % 1_000,
-    );
+/// Writes one JSON record per line for the outcome of a part, or of a whole day if it did not
+/// run at all.
+fn write_record(
+    out: &mut String,
+    solution: &dyn Solution,
+    part: Option<u32>,
+    input: &str,
+    outcome: result::Result<(&Answer, time::Duration), String>,
+) {
+    out.push_str(&format!("{}\n", record(solution, part, input, outcome)));
 }
 
-fn time<F: Fn(A) -> B, A, B>(f: F, a: A) -> B {
-    let now = time::Instant::now();
-    let res = f(a);
-    let d = now.elapsed();
-    print_time(d);
-    res
+fn input_path(year: u32, day: u32) -> String {
+    format!("input/{}/day{:02}", year, day)
 }
 
-fn usage() -> Result<()> {
-    eprintln!("usage: aoc2022 <day> [<input>]");
-    Err(Error::boxed(UsageError {}))
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|err| Error::io("-", err))?;
+    Ok(input)
 }
 
-fn main() -> Result<()> {
-    let (day, input) = {
-        let mut args = env::args().skip(1);
-        let d = if let Some(d) = args.next() {
-            if let Ok(d) = d.parse() {
-                d
-            } else {
-                eprintln!("Could not parse day: '{}'", d);
-                return usage();
-            }
-        } else {
-            eprintln!("Not enough arguments");
-            return usage();
-        };
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    /// One JSON record per line and part.
+    Json,
+}
 
-        let i = args.next().unwrap_or_else(|| format!("input/day{:02}", d));
-        let i = if let Ok(i) = fs::read_to_string(&i) {
-            i
-        } else {
-            eprintln!("No such file: '{}'", &i);
-            return usage();
-        };
+/// How the frames of a day are shown when visualizing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visualize {
+    /// Animated in the terminal.
+    Ascii,
+    /// One image per frame.
+    Ppm,
+    /// One animated image per part.
+    Gif,
+}
 
-        (d, i)
-    };
+/// Command line options shared by all modes.
+#[derive(Debug)]
+struct Options {
+    /// The year of the days to run.
+    year: u32,
+    /// Only run this part, if set.
+    part: Option<u32>,
+    format: Format,
+    /// Number of untimed runs before benchmarking.
+    warmup: usize,
+    /// Number of timed runs when benchmarking.
+    runs: usize,
+    /// Time spent on each benchmark instead of a fixed number of runs, if set.
+    budget: Option<time::Duration>,
+    /// Path of the benchmark baseline to compare against.
+    baseline: String,
+    /// Whether to save benchmark results to the baseline.
+    save_baseline: bool,
+    /// Slowdown in percent above which a benchmark is flagged as a regression.
+    threshold: f64,
+    /// Path of the answers manifest to verify against.
+    answers: String,
+    /// Whether to run a day against its examples instead of its input.
+    example: bool,
+    /// Values of the day's parameters to use instead of the defaults.
+    params: Overrides,
+    /// Number of parts to run at the same time when running all days.
+    jobs: usize,
+    /// How to show the frames of the day's simulations, if at all.
+    visualize: Option<Visualize>,
+    /// Directory images of frames are written to.
+    output: String,
+    /// Frames shown per second when animating.
+    fps: u32,
+    /// Seed of the generated input, random if not set.
+    seed: Option<u64>,
+    /// Size of the generated input, such as its number of lines.
+    size: usize,
+}
 
-    match day {
-        1 => {
-            println!("Part 1: {}", time(day01::part1, input.trim())?);
-            println!("Part 2: {}", time(day01::part2, input.trim())?);
-        }
-        2 => {
-            println!("Part 1: {}", time(day02::part1, input.trim())?);
-            println!("Part 2: {}", time(day02::part2, input.trim())?);
-        }
-        3 => {
-            println!("Part 1: {}", time(day03::part1, input.trim())?);
-            println!("Part 2: {}", time(day03::part2, input.trim())?);
-        }
-        4 => {
-            println!("Part 1: {}", time(day04::part1, input.trim())?);
-            println!("Part 2: {}", time(day04::part2, input.trim())?);
-        }
-        5 => {
-            println!("Part 1: {}", time(day05::part1, input.trim())?);
-            println!("Part 2: {}", time(day05::part2, input.trim())?);
-        }
-        6 => {
-            println!("Part 1: {}", time(day06::part1, input.trim())?);
-            println!("Part 2: {}", time(day06::part2, input.trim())?);
-        }
-        7 => {
-            println!("Part 1: {}", time(day07::part1, input.trim())?);
-            println!("Part 2: {}", time(day07::part2, input.trim())?);
-        }
-        8 => {
-            println!("Part 1: {}", time(day08::part1, input.trim())?);
-            println!("Part 2: {}", time(day08::part2, input.trim())?);
-        }
-        9 => {
-            println!("Part 1: {}", time(day09::part1, input.trim())?);
-            println!("Part 2: {}", time(day09::part2, input.trim())?);
-        }
-        10 => {
-            println!("Part 1: {}", time(day10::part1, input.trim())?);
-            println!("Part 2: \n{}", time(day10::part2, input.trim())?);
-        }
-        11 => {
-            println!("Part 1: {}", time(day11::part1, input.trim())?);
-            println!("Part 2: {}", time(day11::part2, input.trim())?);
-        }
-        12 => {
-            println!("Part 1: {}", time(day12::part1, input.trim())?);
-            println!("Part 2: {}", time(day12::part2, input.trim())?);
-        }
-        13 => {
-            println!("Part 1: {}", time(day13::part1, input.trim())?);
-            println!("Part 2: {}", time(day13::part2, input.trim())?);
-        }
-        14 => {
-            println!("Part 1: {}", time(day14::part1, input.trim())?);
-            println!("Part 2: {}", time(day14::part2, input.trim())?);
-        }
-        15 => {
-            println!("Part 1: {}", time(day15::part1, input.trim())?);
-            println!("Part 2: {}", time(day15::part2, input.trim())?);
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            year: solution::DEFAULT_YEAR,
+            part: None,
+            format: Format::Text,
+            warmup: 1,
+            runs: 10,
+            budget: None,
+            baseline: "target/baseline.json".to_string(),
+            save_baseline: false,
+            threshold: 10.0,
+            answers: answers::MANIFEST.to_string(),
+            example: false,
+            params: Overrides::default(),
+            jobs: 1,
+            visualize: None,
+            output: "target/visualize".to_string(),
+            fps: 30,
+            seed: None,
+            size: 100,
         }
-        16 => {
-            println!("Part 1: {}", time(day16::part1, input.trim())?);
-            println!("Part 2: {}", time(day16::part2, input.trim())?);
-        }
-        17 => {
-            println!("Part 1: {}", time(day17::part1, input.trim())?);
-            println!("Part 2: {}", time(day17::part2, input.trim())?);
-        }
-        18 => {
-            println!("Part 1: {}", time(day18::part1, input.trim())?);
-            println!("Part 2: {}", time(day18::part2, input.trim())?);
-        }
-        19 => {
-            println!("Part 1: {}", time(day19::part1, input.trim())?);
-            println!("Part 2: {}", time(day19::part2, input.trim())?);
-        }
-        20 => {
-            println!("Part 1: {}", time(day20::part1, input.trim())?);
-            println!("Part 2: {}", time(day20::part2, input.trim())?);
-        }
-        21 => {
-            println!("Part 1: {}", time(day21::part1, input.trim())?);
-            println!("Part 2: {}", time(day21::part2, input.trim())?);
-        }
-        22 => {
-            println!("Part 1: {}", time(day22::part1, input.trim())?);
-            println!("Part 2: {}", time(day22::part2, input.trim())?);
-        }
-        23 => {
-            println!("Part 1: {}", time(day23::part1, input.trim())?);
-            println!("Part 2: {}", time(day23::part2, input.trim())?);
-        }
-        24 => {
-            println!("Part 1: {}", time(day24::part1, input.trim())?);
-            println!("Part 2: {}", time(day24::part2, input.trim())?);
-        }
-        25 => {
-            println!("Part 1: {}", time(day25::part1, input.trim())?);
-        }
-        _ => unimplemented!(),
     }
-
-    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn day01p1() {
-        let inp = include_str!("../input/day01");
-        assert_eq!(crate::day01::part1(inp.trim()).unwrap(), 72070);
-    }
-
-    #[test]
-    fn day01p2() {
-        let inp = include_str!("../input/day01");
-        assert_eq!(crate::day01::part2(inp.trim()).unwrap(), 211805);
-    }
-
-    #[test]
-    fn day02p1() {
-        let inp = include_str!("../input/day02");
-        assert_eq!(crate::day02::part1(inp.trim()).unwrap(), 9759);
+fn list(opts: &Options) -> Result<()> {
+    for solution in solution::all(opts.year) {
+        let params = solution.params(&Overrides::default())?;
+        if params.is_empty() {
+            println!("{:2}: {}", solution.day(), solution.name());
+        } else {
+            let params: Vec<_> = params
+                .iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect();
+            let params = params.join(", ");
+            println!("{:2}: {} ({})", solution.day(), solution.name(), params);
+        }
     }
+    Ok(())
+}
 
-    #[test]
-    fn day02p2() {
-        let inp = include_str!("../input/day02");
-        assert_eq!(crate::day02::part2(inp.trim()).unwrap(), 12429);
-    }
+/// The parts selected by `opts`.
+fn parts(opts: &Options) -> impl Iterator<Item = u32> + '_ {
+    (1..=2).filter(|part| opts.part.is_none_or(|p| p == *part))
+}
 
-    #[test]
-    fn day03p1() {
-        let inp = include_str!("../input/day03");
-        assert_eq!(crate::day03::part1(inp.trim()).unwrap(), 7845);
-    }
+/// Runs `part` of `solution` on `input` with the parameters in `opts`, returning its answer,
+/// `None` if the day has no such part, along with the time it took.
+fn solve(
+    solution: &dyn Solution,
+    input: &str,
+    part: u32,
+    opts: &Options,
+) -> (Option<Result<Answer>>, time::Duration) {
+    time(|i| answers::solve(solution, i, &opts.params, part), input)
+}
 
-    #[test]
-    fn day03p2() {
-        let inp = include_str!("../input/day03");
-        assert_eq!(crate::day03::part2(inp.trim()).unwrap(), 2790);
+/// Writes the outcome of `part` of `solution`, run on the input read from `path`, to `out`,
+/// returning the error of the part after writing it.
+fn report(
+    out: &mut String,
+    solution: &dyn Solution,
+    path: &str,
+    part: u32,
+    (answer, d): (Result<Answer>, time::Duration),
+    opts: &Options,
+) -> Result<()> {
+    match opts.format {
+        Format::Text => {
+            write_time(out, d);
+            write_answer(out, part, &answer?);
+        }
+        Format::Json => {
+            let outcome = answer.as_ref().map(|a| (a, d)).map_err(|e| e.to_string());
+            write_record(out, solution, Some(part), path, outcome);
+            answer?;
+        }
     }
+    Ok(())
+}
 
-    #[test]
-    fn day04p1() {
-        let inp = include_str!("../input/day04");
-        assert_eq!(crate::day04::part1(inp.trim()).unwrap(), 569);
+/// Runs the parts of `solution` selected by `opts` on the input read from `path`, returning the
+/// time each part took.
+fn run(
+    solution: &dyn Solution,
+    input: &str,
+    path: &str,
+    opts: &Options,
+) -> Result<[Option<time::Duration>; 2]> {
+    let mut times = [None; 2];
+    for part in parts(opts) {
+        if let (Some(answer), d) = solve(solution, input, part, opts) {
+            let mut out = String::new();
+            let reported = report(&mut out, solution, path, part, (answer, d), opts);
+            print!("{}", out);
+            reported?;
+            times[part as usize - 1] = Some(d);
+        }
     }
+    Ok(times)
+}
 
-    #[test]
-    fn day04p2() {
-        let inp = include_str!("../input/day04");
-        assert_eq!(crate::day04::part2(inp.trim()).unwrap(), 936);
-    }
+/// Runs the parts of `solution` selected by `opts` on the input read from `path` like [`run`],
+/// showing the frames of the parts that are visualized as `how` says, and returning the time each
+/// of them took.
+fn visualize(
+    solution: &dyn Solution,
+    input: &str,
+    path: &str,
+    how: Visualize,
+    opts: &Options,
+) -> Result<[Option<time::Duration>; 2]> {
+    let delay = time::Duration::from_secs(1) / opts.fps;
+    let mut times = [None; 2];
+    for part in parts(opts) {
+        let name = format!("{}/day{:02}-{}", opts.output, solution.day(), part);
+        let (mut sink, name): (Box<dyn Sink>, _) = match how {
+            Visualize::Ascii => (Box::new(Ascii::new(delay)), None),
+            Visualize::Ppm => (Box::new(Ppm::new(&name)), Some(name)),
+            Visualize::Gif => {
+                let name = format!("{}.gif", name);
+                (Box::new(Gif::new(&name, delay)), Some(name))
+            }
+        };
 
-    #[test]
-    fn day05p1() {
-        let inp = include_str!("../input/day05");
-        assert_eq!(crate::day05::part1(inp.trim()).unwrap(), "GFTNRBZPF");
-    }
+        let start = time::Instant::now();
+        let mut frames = Frames::new(sink.as_mut());
+        let Some(answer) = answers::visualize(solution, input, &opts.params, part, &mut frames)
+        else {
+            continue;
+        };
+        let d = start.elapsed();
+        // nothing is written for a part that failed
+        let written = match answer {
+            Ok(_) => Some(sink.finish()?),
+            Err(_) => None,
+        };
 
-    #[test]
-    fn day05p2() {
-        let inp = include_str!("../input/day05");
-        assert_eq!(crate::day05::part2(inp.trim()).unwrap(), "VRQWPDSGP");
+        let mut out = String::new();
+        let reported = report(&mut out, solution, path, part, (answer, d), opts);
+        if let (Some(written), Some(name), Format::Text) = (written, name, opts.format) {
+            out.push_str(&format!("Wrote {} frames to '{}'\n", written, name));
+        }
+        print!("{}", out);
+        reported?;
+        times[part as usize - 1] = Some(d);
     }
+    Ok(times)
+}
 
-    #[test]
-    fn day06p1() {
-        let inp = include_str!("../input/day06");
-        assert_eq!(crate::day06::part1(inp.trim()).unwrap(), 1542);
+/// Outcome of a part run as part of all days: its answer, `None` if the day has no such part,
+/// and the time it took. `None` if there is no input for the day.
+type Outcome = Option<(Option<Result<Answer>>, time::Duration)>;
+
+/// Writes the outcomes of the parts of a day, run on the input read from `path`, in the order
+/// of the parts, returning the time each part took, or why the day did not run.
+fn report_day(
+    out: &mut String,
+    solution: &dyn Solution,
+    path: &str,
+    outcomes: Vec<Outcome>,
+    opts: &Options,
+) -> result::Result<[Option<time::Duration>; 2], &'static str> {
+    let text = opts.format == Format::Text;
+    if text {
+        out.push_str(&format!("Day {}: {}\n", solution.day(), solution.name()));
+    }
+
+    let mut times = [None; 2];
+    let mut status = Ok(());
+    for (part, outcome) in parts(opts).zip(outcomes) {
+        let Some((answer, d)) = outcome else {
+            if text {
+                out.push_str(&format!("Skipped, no such file: '{}'\n", path));
+            } else {
+                write_record(out, solution, None, path, Err("no such file".to_string()));
+            }
+            status = Err("skipped");
+            break;
+        };
+        if let Some(answer) = answer {
+            if let Err(err) = report(out, solution, path, part, (answer, d), opts) {
+                if text {
+                    out.push_str(&format!("Error: {}\n", err));
+                }
+                status = Err("failed");
+                break;
+            }
+            times[part as usize - 1] = Some(d);
+        }
     }
-
-    #[test]
-    fn day06p2() {
-        let inp = include_str!("../input/day06");
-        assert_eq!(crate::day06::part2(inp.trim()).unwrap(), 3153);
+    if text {
+        out.push('\n');
     }
+    status.map(|()| times)
+}
 
-    #[test]
-    fn day07p1() {
-        let inp = include_str!("../input/day07");
-        assert_eq!(crate::day07::part1(inp.trim()).unwrap(), 1743217);
-    }
+/// Runs all days on their inputs, `opts.jobs` parts at a time, printing the outcome of each day
+/// in order once all its parts are done.
+fn all(opts: &Options) -> Result<()> {
+    let start = time::Instant::now();
+    let days = solution::all(opts.year);
+    let inputs: Vec<_> = days
+        .iter()
+        .map(|solution| {
+            let path = input_path(opts.year, solution.day());
+            (fs::read_to_string(&path).ok(), path)
+        })
+        .collect();
+    let tasks: Vec<(usize, u32)> = (0..days.len())
+        .flat_map(|day| parts(opts).map(move |part| (day, part)))
+        .collect();
+    let last = parts(opts).last();
+
+    let mut summary = Vec::new();
+    let mut outcomes = Vec::new();
+    pool::run(
+        opts.jobs,
+        &tasks,
+        |&(day, part)| {
+            let input = inputs[day].0.as_ref()?;
+            Some(solve(days[day], input, part, opts))
+        },
+        |&(day, part), outcome| {
+            outcomes.push(outcome);
+            if Some(part) == last {
+                let mut out = String::new();
+                let outcomes = std::mem::take(&mut outcomes);
+                let status = report_day(&mut out, days[day], &inputs[day].1, outcomes, opts);
+                print!("{}", out);
+                summary.push((days[day], status));
+            }
+        },
+    );
 
-    #[test]
-    fn day07p2() {
-        let inp = include_str!("../input/day07");
-        assert_eq!(crate::day07::part2(inp.trim()).unwrap(), 8319096);
+    if opts.format != Format::Text {
+        return Ok(());
     }
 
-    #[test]
-    fn day08p1() {
-        let inp = include_str!("../input/day08");
-        assert_eq!(crate::day08::part1(inp.trim()).unwrap(), 1715);
+    println!(
+        "{:>3}  {:<26}  {:>17}  {:>17}",
+        "Day", "Name", "Part 1", "Part 2"
+    );
+    let mut total = time::Duration::ZERO;
+    for (solution, status) in summary {
+        let (part1, part2) = match status {
+            Ok(times) => {
+                total += times.iter().flatten().sum::<time::Duration>();
+                let [part1, part2] = times.map(|d| d.map(format_time).unwrap_or_default());
+                (part1, part2)
+            }
+            Err(reason) => (reason.to_string(), String::new()),
+        };
+        println!(
+            "{:>3}  {:<26}  {:>17}  {:>17}",
+            solution.day(),
+            solution.name(),
+            part1,
+            part2
+        );
     }
-
-    #[test]
-    fn day08p2() {
-        let inp = include_str!("../input/day08");
-        assert_eq!(crate::day08::part2(inp.trim()).unwrap(), 374400);
+    println!("Total: {} seconds", format_time(total));
+    if opts.jobs > 1 {
+        let elapsed = format_time(start.elapsed());
+        println!("Elapsed: {} seconds on {} threads", elapsed, opts.jobs);
     }
 
-    #[test]
-    fn day09p1() {
-        let inp = include_str!("../input/day09");
-        assert_eq!(crate::day09::part1(inp.trim()).unwrap(), 5930);
-    }
+    Ok(())
+}
 
-    #[test]
-    fn day09p2() {
-        let inp = include_str!("../input/day09");
-        assert_eq!(crate::day09::part2(inp.trim()).unwrap(), 2443);
+/// Describes where `actual` differs from `expected`, line by line for answers spanning several
+/// lines.
+fn diff(expected: &Answer, actual: &Answer) -> String {
+    let (expected, actual) = (expected.to_string(), actual.to_string());
+    if !expected.contains('\n') && !actual.contains('\n') {
+        return format!("    expected: {}\n    actual:   {}\n", expected, actual);
+    }
+
+    let mut diff = String::new();
+    let (mut expected, mut actual) = (expected.lines(), actual.lines());
+    loop {
+        match (expected.next(), actual.next()) {
+            (None, None) => break,
+            (Some(e), Some(a)) if e == a => diff.push_str(&format!("      {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff.push_str(&format!("    - {}\n", e));
+                }
+                if let Some(a) = a {
+                    diff.push_str(&format!("    + {}\n", a));
+                }
+            }
+        }
     }
+    diff
+}
 
-    #[test]
-    fn day10p1() {
-        let inp = include_str!("../input/day10");
-        assert_eq!(crate::day10::part1(inp.trim()).unwrap(), 13680);
-    }
+/// Counts of checked parts by outcome.
+#[derive(Debug, Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
 
-    #[test]
-    fn day10p2() {
-        let inp = include_str!("../input/day10");
-        let mut exp = String::new();
-        exp.push_str("###..####..##..###..#..#.###..####.###..\n");
-        exp.push_str("#..#....#.#..#.#..#.#.#..#..#.#....#..#.\n");
-        exp.push_str("#..#...#..#....#..#.##...#..#.###..###..\n");
-        exp.push_str("###...#...#.##.###..#.#..###..#....#..#.\n");
-        exp.push_str("#....#....#..#.#....#.#..#....#....#..#.\n");
-        exp.push_str("#....####..###.#....#..#.#....####.###..\n");
-        assert_eq!(crate::day10::part2(inp.trim()).unwrap(), exp);
+impl Tally {
+    /// Prints the counts, unless `opts` asks for JSON records only.
+    fn finish(self, opts: &Options) -> Result<()> {
+        if opts.format == Format::Text {
+            println!(
+                "{} passed, {} failed, {} missing",
+                self.passed, self.failed, self.missing
+            );
+        }
+        if self.failed > 0 {
+            return Err(Error::Verify(self.failed));
+        }
+        Ok(())
     }
+}
 
-    #[test]
-    fn day11p1() {
-        let inp = include_str!("../input/day11");
-        assert_eq!(crate::day11::part1(inp.trim()).unwrap(), 117640);
-    }
+/// Checks the parts of `solution` selected by `opts` against the answers given by `example`.
+/// Parts without an answer are only run if `unanswered` is set, and are then reported as missing.
+fn check(
+    solution: &dyn Solution,
+    example: &Example,
+    unanswered: bool,
+    opts: &Options,
+    tally: &mut Tally,
+) {
+    for part in 1..=2 {
+        let answer = &example.parts[part as usize - 1];
+        if opts.part.is_some_and(|p| p != part) || (answer.is_none() && !unanswered) {
+            continue;
+        }
 
-    #[test]
-    fn day11p2() {
-        let inp = include_str!("../input/day11");
-        assert_eq!(crate::day11::part2(inp.trim()).unwrap(), 30616425600);
-    }
+        let (actual, d) = time(
+            |i| answers::solve(solution, i, &example.params, part),
+            &example.input,
+        );
+        let (status, detail) = match (&actual, answer) {
+            (None, None) => continue,
+            (Some(Ok(actual)), Some(answer)) if answers::matches(actual, answer) => {
+                tally.passed += 1;
+                ("PASS", String::new())
+            }
+            (Some(Ok(actual)), Some(answer)) => {
+                tally.failed += 1;
+                ("FAIL", diff(answer, actual))
+            }
+            (Some(Ok(actual)), None) => {
+                tally.missing += 1;
+                ("MISSING", format!("    actual:   {}\n", actual))
+            }
+            (Some(Err(err)), _) => {
+                tally.failed += 1;
+                ("FAIL", format!("    error: {}\n", err))
+            }
+            (None, Some(_)) => {
+                tally.failed += 1;
+                ("FAIL", "    no such part\n".to_string())
+            }
+        };
+        if opts.format == Format::Json {
+            let outcome = match &actual {
+                Some(Ok(actual)) => Ok((actual, d)),
+                Some(Err(err)) => Err(err.to_string()),
+                None => Err("no such part".to_string()),
+            };
+            let mut record = record(solution, Some(part), &example.path, outcome);
+            if let json::Value::Object(fields) = &mut record {
+                let expected = answer.as_ref().map_or(json::Value::Null, answer_value);
+                fields.push(("expected".to_string(), expected));
+                fields.push(("status".to_string(), status.into()));
+            }
+            println!("{}", record);
+            continue;
+        }
 
-    #[test]
-    fn day12p1() {
-        let inp = include_str!("../input/day12");
-        assert_eq!(crate::day12::part1(inp.trim()).unwrap(), 394);
+        println!(
+            "{:<7}  day {:2} part {}  {}  {} seconds",
+            status,
+            solution.day(),
+            part,
+            example.path,
+            format_time(d)
+        );
+        print!("{}", detail);
     }
+}
 
-    #[test]
-    fn day12p2() {
-        let inp = include_str!("../input/day12");
-        assert_eq!(crate::day12::part2(inp.trim()).unwrap(), 388);
+/// Checks `days`, or all days if empty, against the answers manifest. Parts without a recorded
+/// answer are reported as missing, which does not fail verification.
+fn verify(days: &[u32], opts: &Options) -> Result<()> {
+    let mut manifest = answers::load(&opts.answers)?;
+    manifest.retain(|e| e.year == opts.year);
+    let mut tally = Tally::default();
+
+    for expected in manifest
+        .iter()
+        .filter(|e| solution::get(opts.year, e.day).is_none())
+    {
+        if days.is_empty() || days.contains(&expected.day) {
+            println!("FAIL     day {:2}         {}", expected.day, expected.input);
+            println!("    no such day");
+            tally.failed += 1;
+        }
     }
 
-    #[test]
-    fn day13p1() {
-        let inp = include_str!("../input/day13");
-        assert_eq!(crate::day13::part1(inp.trim()).unwrap(), 5659);
-    }
+    for solution in solution::all(opts.year) {
+        let day = solution.day();
+        if !days.is_empty() && !days.contains(&day) {
+            continue;
+        }
 
-    #[test]
-    fn day13p2() {
-        let inp = include_str!("../input/day13");
-        assert_eq!(crate::day13::part2(inp.trim()).unwrap(), 22110);
-    }
+        let entries: Vec<_> = manifest.iter().filter(|e| e.day == day).collect();
+        if entries.is_empty() {
+            println!("MISSING  day {:2}", day);
+            println!("    no answers recorded");
+            tally.missing += 1;
+        }
 
-    #[test]
-    fn day14p1() {
-        let inp = include_str!("../input/day14");
-        assert_eq!(crate::day14::part1(inp.trim()).unwrap(), 901);
+        for expected in entries {
+            match fs::read_to_string(&expected.input) {
+                Ok(input) => {
+                    let example = Example {
+                        path: expected.input.clone(),
+                        input,
+                        params: Overrides::default(),
+                        parts: expected.parts.clone(),
+                    };
+                    check(*solution, &example, true, opts, &mut tally);
+                }
+                Err(err) => {
+                    println!("FAIL     day {:2}         {}", day, expected.input);
+                    println!("    {}", err);
+                    tally.failed += 1;
+                }
+            }
+        }
     }
 
-    #[test]
-    fn day14p2() {
-        let inp = include_str!("../input/day14");
-        assert_eq!(crate::day14::part2(inp.trim()).unwrap(), 24589);
-    }
+    tally.finish(opts)
+}
 
-    #[test]
-    fn day15p1() {
-        let inp = include_str!("../input/day15");
-        assert_eq!(crate::day15::part1(inp.trim()).unwrap(), 4985193);
+/// Prints a random input for `solution` generated as configured by `opts`, for the parameters in
+/// it, along with the seed on stderr if it was picked at random, so that the input can be
+/// generated again.
+fn generate(solution: &dyn Solution, opts: &Options) -> Result<()> {
+    let seed = opts.seed.unwrap_or_else(|| {
+        let now = time::SystemTime::now().duration_since(time::UNIX_EPOCH);
+        now.map_or(0, |d| d.as_nanos() as u64)
+    });
+    let Some(input) = solution.generate(&mut Rng::new(seed), opts.size, &opts.params) else {
+        return usage(format!("Day {} has no input generator", solution.day()));
+    };
+    let input = input?;
+    if opts.seed.is_none() {
+        eprintln!("Seed: {}", seed);
     }
+    println!("{}", input);
+    Ok(())
+}
 
-    #[test]
-    fn day15p2() {
-        let inp = include_str!("../input/day15");
-        assert_eq!(crate::day15::part2(inp.trim()).unwrap(), 11583882601918);
-    }
+/// Checks `solution` against the answers given by its examples, with the parameters in `opts`
+/// overriding those of the examples. Parts an example gives no answer for are not run.
+fn example(solution: &dyn Solution, opts: &Options) -> Result<()> {
+    let examples = examples::load(examples::DIR, opts.year, solution.day())?;
+    if examples.is_empty() {
+        let pattern = format!(
+            "{}/{}/day{:02}-*.txt",
+            examples::DIR,
+            opts.year,
+            solution.day()
+        );
+        return Err(Error::io(pattern, io::ErrorKind::NotFound.into()));
+    }
+
+    let mut tally = Tally::default();
+    for mut example in examples {
+        example.params.extend(&opts.params);
+        check(solution, &example, false, opts, &mut tally);
+    }
+    tally.finish(opts)
+}
 
-    #[test]
-    fn day16p1() {
-        let inp = include_str!("../input/day16");
-        assert_eq!(crate::day16::part1(inp.trim()).unwrap(), 1653);
-    }
+fn print_usage() {
+    eprintln!("usage: aoc [<year>] <day> [<input> | - | --example] [--part <part>]");
+    eprintln!("                   [--format text|json] [--param <name>=<value>...]");
+    eprintln!("                   [--visualize ascii|ppm|gif] [--output <dir>] [--fps <frames>]");
+    eprintln!("       aoc [<year>] all [--part <part>] [--format text|json] [--jobs <threads>]");
+    eprintln!("       aoc [<year>] bench <day> [<input> | -] [--part <part>] [--warmup <runs>]");
+    eprintln!("                            [--param <name>=<value>...]");
+    eprintln!("                            [--runs <runs> | --time <seconds>] [--baseline <path>]");
+    eprintln!("                            [--save-baseline] [--threshold <percent>]");
+    eprintln!("       aoc [<year>] watch <day> [--example] [--part <part>]");
+    eprintln!("                            [--param <name>=<value>...]");
+    eprintln!("       aoc [<year>] gen <day> [--seed <n>] [--size <n>]");
+    eprintln!("                        [--param <name>=<value>...]");
+    eprintln!("       aoc [<year>] verify [<day>...] [--part <part>] [--answers <path>]");
+    eprintln!("       aoc [<year>] new <day> [<name>]");
+    eprintln!("       aoc [<year>] list");
+    eprintln!("The year defaults to {}.", solution::DEFAULT_YEAR);
+}
 
-    #[test]
-    fn day16p2() {
-        let inp = include_str!("../input/day16");
-        assert_eq!(crate::day16::part2(inp.trim()).unwrap(), 2223);
-    }
+fn usage(msg: impl Into<String>) -> Result<()> {
+    Err(Error::Usage(msg.into()))
+}
 
-    #[test]
-    fn day17p1() {
-        let inp = include_str!("../input/day17");
-        assert_eq!(crate::day17::part1(inp.trim()).unwrap(), 3217);
-    }
+fn main() -> ExitCode {
+    let err = match try_main() {
+        Ok(()) => return ExitCode::SUCCESS,
+        Err(err) => err,
+    };
 
-    #[test]
-    fn day17p2() {
-        let inp = include_str!("../input/day17");
-        assert_eq!(crate::day17::part2(inp.trim()).unwrap(), 1585673352422);
+    eprintln!("Error: {}", err);
+    let mut source = error::Error::source(&err);
+    while let Some(err) = source {
+        eprintln!("  caused by: {}", err);
+        source = err.source();
     }
-
-    #[test]
-    fn day18p1() {
-        let inp = include_str!("../input/day18");
-        assert_eq!(crate::day18::part1(inp.trim()).unwrap(), 3550);
+    if let Error::Usage(_) = err {
+        print_usage();
     }
+    ExitCode::from(err.exit_code())
+}
 
-    #[test]
-    fn day18p2() {
-        let inp = include_str!("../input/day18");
-        assert_eq!(crate::day18::part2(inp.trim()).unwrap(), 2028);
-    }
+fn try_main() -> Result<()> {
+    let (mut args, opts) = {
+        let mut args = env::args().skip(1);
+        let mut positional = Vec::new();
+        let mut opts = Options::default();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    opts.part = match args.next().map(|p| p.parse()) {
+                        Some(Ok(p @ 1..=2)) => Some(p),
+                        _ => {
+                            return usage("Invalid part, expected 1 or 2");
+                        }
+                    }
+                }
+                "--format" => {
+                    opts.format = match args.next().as_deref() {
+                        Some("text") => Format::Text,
+                        Some("json") => Format::Json,
+                        _ => {
+                            return usage("Invalid format, expected 'text' or 'json'");
+                        }
+                    }
+                }
+                "--warmup" | "--runs" => {
+                    let n = if let Some(Ok(n)) = args.next().map(|n| n.parse()) {
+                        n
+                    } else {
+                        return usage(format!("Invalid number of runs for {}", arg));
+                    };
+                    if arg == "--warmup" {
+                        opts.warmup = n;
+                    } else {
+                        opts.runs = n;
+                    }
+                }
+                "--time" => {
+                    opts.budget = match args.next().map(|s| s.parse()) {
+                        Some(Ok(s)) if s > 0.0 => Some(time::Duration::from_secs_f64(s)),
+                        _ => {
+                            return usage("Invalid time, expected a positive number of seconds");
+                        }
+                    }
+                }
+                "--baseline" => {
+                    opts.baseline = if let Some(path) = args.next() {
+                        path
+                    } else {
+                        return usage("Missing baseline path");
+                    }
+                }
+                "--save-baseline" => opts.save_baseline = true,
+                "--example" => opts.example = true,
+                "--visualize" => {
+                    opts.visualize = match args.next().as_deref() {
+                        Some("ascii") => Some(Visualize::Ascii),
+                        Some("ppm") => Some(Visualize::Ppm),
+                        Some("gif") => Some(Visualize::Gif),
+                        _ => {
+                            return usage(
+                                "Invalid visualization, expected 'ascii', 'ppm' or 'gif'",
+                            );
+                        }
+                    }
+                }
+                "--output" => {
+                    opts.output = if let Some(dir) = args.next() {
+                        dir
+                    } else {
+                        return usage("Missing output directory");
+                    }
+                }
+                "--fps" => {
+                    opts.fps = match args.next().map(|n| n.parse()) {
+                        Some(Ok(n)) if n > 0 => n,
+                        _ => {
+                            return usage("Invalid frame rate, expected a positive number");
+                        }
+                    }
+                }
+                "--seed" => {
+                    opts.seed = match args.next().map(|n| n.parse()) {
+                        Some(Ok(n)) => Some(n),
+                        _ => {
+                            return usage("Invalid seed, expected a number");
+                        }
+                    }
+                }
+                "--size" => {
+                    opts.size = match args.next().map(|n| n.parse()) {
+                        Some(Ok(n)) => n,
+                        _ => {
+                            return usage("Invalid size, expected a number");
+                        }
+                    }
+                }
+                "--jobs" => {
+                    opts.jobs = match args.next().map(|n| n.parse()) {
+                        Some(Ok(n)) if n > 0 => n,
+                        _ => {
+                            return usage("Invalid number of jobs, expected a positive number");
+                        }
+                    }
+                }
+                "--param" => {
+                    if args.next().and_then(|p| opts.params.insert(&p)).is_none() {
+                        return usage("Invalid parameter, expected <name>=<value>");
+                    }
+                }
+                "--answers" => {
+                    opts.answers = if let Some(path) = args.next() {
+                        path
+                    } else {
+                        return usage("Missing answers path");
+                    }
+                }
+                "--threshold" => {
+                    opts.threshold = match args.next().map(|t| t.parse()) {
+                        Some(Ok(t)) if t >= 0.0 => t,
+                        _ => {
+                            return usage("Invalid threshold, expected a percentage");
+                        }
+                    }
+                }
+                _ => positional.push(arg),
+            }
+        }
+        // a leading year selects the event, as days only go up to 25
+        if let Some(Ok(year @ 2015..)) = positional.first().map(|y| y.parse()) {
+            opts.year = year;
+            positional.remove(0);
+        }
+        (positional.into_iter(), opts)
+    };
 
-    #[test]
-    fn day19p1() {
-        let inp = include_str!("../input/day19");
-        assert_eq!(crate::day19::part1(inp.trim()).unwrap(), 1550);
-    }
+    let (mut bench, mut watching, mut generating) = (false, false, false);
+    let (solution, input, path) = {
+        let mut d = args.next();
+        if d.as_deref() == Some("bench") {
+            bench = true;
+            d = args.next();
+        } else if d.as_deref() == Some("watch") {
+            watching = true;
+            d = args.next();
+        } else if d.as_deref() == Some("gen") {
+            generating = true;
+            d = args.next();
+        }
 
-    #[test]
-    fn day19p2() {
-        let inp = include_str!("../input/day19");
-        assert_eq!(crate::day19::part2(inp.trim()).unwrap(), 18630);
-    }
+        let d = if let Some(d) = d {
+            if d != "new" && solution::all(opts.year).is_empty() {
+                return usage(format!("No such year: {}", opts.year));
+            }
 
-    #[test]
-    fn day20p1() {
-        let inp = include_str!("../input/day20");
-        assert_eq!(crate::day20::part1(inp.trim()).unwrap(), 3700);
-    }
+            if !opts.params.is_empty() && ["list", "all", "verify", "new"].contains(&d.as_str()) {
+                return usage("Parameters can only be given when running a single day");
+            }
+            if opts.visualize.is_some()
+                && (bench
+                    || watching
+                    || generating
+                    || ["list", "all", "verify", "new"].contains(&d.as_str()))
+            {
+                return usage("Only a single day run on its input can be visualized");
+            }
 
-    #[test]
-    fn day20p2() {
-        let inp = include_str!("../input/day20");
-        assert_eq!(crate::day20::part2(inp.trim()).unwrap(), 10626948369382);
-    }
+            if d == "list" {
+                return list(&opts);
+            } else if d == "all" {
+                return all(&opts);
+            } else if d == "verify" {
+                let mut days = Vec::new();
+                for d in args {
+                    match d.parse() {
+                        Ok(d) if solution::get(opts.year, d).is_some() => days.push(d),
+                        _ => {
+                            return usage(format!("No such day: '{}'", d));
+                        }
+                    }
+                }
+                return verify(&days, &opts);
+            } else if d == "new" {
+                let day = match args.next().map(|d| d.parse()) {
+                    Some(Ok(day @ 1..=25)) => day,
+                    _ => return usage("Invalid day, expected 1 to 25"),
+                };
+                let name = args.collect::<Vec<_>>().join(" ");
+                if name.is_empty() {
+                    return scaffold::new(opts.year, day, &format!("Day {}", day));
+                }
+                return scaffold::new(opts.year, day, &name);
+            } else if let Ok(d) = d.parse() {
+                d
+            } else {
+                return usage(format!("Could not parse day: '{}'", d));
+            }
+        } else {
+            return usage("Not enough arguments");
+        };
 
-    #[test]
-    fn day21p1() {
-        let inp = include_str!("../input/day21");
-        assert_eq!(crate::day21::part1(inp.trim()).unwrap(), 256997859093114);
-    }
+        let s = if let Some(s) = solution::get(opts.year, d) {
+            s
+        } else {
+            return usage(format!("No such day: {}", d));
+        };
 
-    #[test]
-    fn day21p2() {
-        let inp = include_str!("../input/day21");
-        assert_eq!(crate::day21::part2(inp.trim()).unwrap(), 3952288690726);
-    }
+        // reject unknown parameters and invalid values before reading any input
+        s.params(&opts.params)?;
 
-    #[test]
-    fn day22p1() {
-        let inp = include_str!("../input/day22");
-        assert_eq!(crate::day22::part1(inp).unwrap(), 76332);
-    }
+        if watching {
+            if let Some(path) = args.next() {
+                return usage(format!(
+                    "Watch mode does not take an input, found '{}'",
+                    path
+                ));
+            }
+            return watch::watch(opts.year, d, &opts);
+        }
 
-    #[test]
-    fn day22p2() {
-        let inp = include_str!("../input/day22");
-        assert_eq!(crate::day22::part2(inp).unwrap(), 144012);
-    }
+        if generating {
+            if opts.example {
+                return usage("Generating an input does not support --example");
+            }
+            if let Some(arg) = args.next() {
+                return usage(format!(
+                    "Generating an input does not take an input, found '{}'",
+                    arg
+                ));
+            }
+            return generate(s, &opts);
+        }
 
-    #[test]
-    fn day23p1() {
-        let inp = include_str!("../input/day23");
-        assert_eq!(crate::day23::part1(inp.trim()).unwrap(), 4068);
-    }
+        if opts.example {
+            if opts.visualize.is_some() {
+                return usage("Only a single day run on its input can be visualized");
+            }
+            if bench {
+                return usage("Benchmarking does not support --example");
+            }
+            return example(s, &opts);
+        }
 
-    #[test]
-    fn day23p2() {
-        let inp = include_str!("../input/day23");
-        assert_eq!(crate::day23::part2(inp.trim()).unwrap(), 968);
-    }
+        let path = args.next();
+        let stdin = match path.as_deref() {
+            Some("-") => Some(read_stdin()?),
+            Some(_) => None,
+            // use piped input if there is any, the default input otherwise
+            None if !io::stdin().is_terminal() => Some(read_stdin()?).filter(|i| !i.is_empty()),
+            None => None,
+        };
 
-    #[test]
-    fn day24p1() {
-        let inp = include_str!("../input/day24");
-        assert_eq!(crate::day24::part1(inp.trim()).unwrap(), 343);
-    }
+        let (i, path) = if let Some(i) = stdin {
+            (i, "-".to_string())
+        } else {
+            let path = path.unwrap_or_else(|| input_path(opts.year, d));
+            let i = fs::read_to_string(&path).map_err(|err| Error::io(&path, err))?;
+            (i, path)
+        };
 
-    #[test]
-    fn day24p2() {
-        let inp = include_str!("../input/day24");
-        assert_eq!(crate::day24::part2(inp.trim()).unwrap(), 960);
-    }
+        (s, i, path)
+    };
 
-    #[test]
-    fn day25p1() {
-        let inp = include_str!("../input/day25");
-        assert_eq!(crate::day25::part1(inp.trim()).unwrap(), "2-121-=10=200==2==21");
+    let ran = if bench {
+        bench::bench(solution, &input, &opts)?.map(|stats| stats.is_some())
+    } else if let Some(how) = opts.visualize {
+        let ran = visualize(solution, &input, &path, how, &opts)?.map(|d| d.is_some());
+        if !ran.contains(&true) {
+            return usage(match opts.part {
+                Some(part) => format!(
+                    "Day {} has no visualization of part {}",
+                    solution.day(),
+                    part
+                ),
+                None => format!("Day {} has no visualization", solution.day()),
+            });
+        }
+        ran
+    } else {
+        run(solution, &input, &path, &opts)?.map(|d| d.is_some())
+    };
+    if let Some(part) = opts.part {
+        if !ran[part as usize - 1] {
+            return usage(format!("Day {} has no part {}", solution.day(), part));
+        }
     }
+    Ok(())
 }
-
-// vim macro to prepare new day..
-// }kyyp/unimplky3k3jp/daynG{ky2{Pzt7nnnnnn
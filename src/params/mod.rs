@@ -0,0 +1,78 @@
+use std::str::FromStr;
+
+/// Values given for a day's parameters as `name=value`, such as with `--param y=10`, overriding
+/// the defaults of the day's `Params`, which are those of the actual puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Overrides(Vec<(String, String)>);
+
+impl Overrides {
+    /// Adds the value of `pair`, which is `name=value`, replacing an earlier value of the same
+    /// parameter. `None` if `pair` is not of that form.
+    pub fn insert(&mut self, pair: &str) -> Option<()> {
+        let (name, value) = pair.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        self.0.retain(|(n, _)| n != name);
+        self.0.push((name.to_string(), value.trim().to_string()));
+        Some(())
+    }
+
+    /// Adds all values of `other`, replacing those of the same parameters.
+    pub fn extend(&mut self, other: &Overrides) {
+        for (name, value) in other.iter() {
+            self.0.retain(|(n, _)| n != name);
+            self.0.push((name.to_string(), value.to_string()));
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The names and values, in the order they were given.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(n, v)| (n.as_str(), v.as_str()))
+    }
+}
+
+/// Parses `value` for the parameter `name` of `day`.
+pub fn parse<T: FromStr>(day: u32, name: &str, value: &str) -> crate::Result<T> {
+    value.parse().map_err(|_| {
+        crate::Error::Usage(format!(
+            "Invalid value for parameter '{}' of day {}: '{}'",
+            name, day, value
+        ))
+    })
+}
+
+/// The error for a value given for `name`, which is not a parameter of `day`.
+pub fn unknown(day: u32, name: &str) -> crate::Error {
+    crate::Error::Usage(format!("Day {} has no parameter '{}'", day, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides() {
+        let mut overrides = Overrides::default();
+        assert_eq!(overrides.insert("y=10"), Some(()));
+        assert_eq!(overrides.insert("max = 20"), Some(()));
+        assert_eq!(overrides.insert("y=11"), Some(()));
+        assert_eq!(overrides.insert("y"), None);
+        assert_eq!(overrides.insert("=1"), None);
+        assert_eq!(
+            overrides.iter().collect::<Vec<_>>(),
+            [("max", "20"), ("y", "11")]
+        );
+
+        assert_eq!(parse::<i64>(15, "y", "-3").unwrap(), -3);
+        assert_eq!(
+            parse::<u32>(15, "y", "-3").unwrap_err().to_string(),
+            "Invalid value for parameter 'y' of day 15: '-3'"
+        );
+    }
+}
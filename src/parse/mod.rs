@@ -0,0 +1,156 @@
+use std::{error, fmt, str::FromStr};
+
+/// Malformed puzzle input, located by line and column (both starting at 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub day: u32,
+    pub line: usize,
+    pub col: usize,
+    pub msg: String,
+}
+
+/// Line and column of `offset` into `s`.
+fn locate(s: &str, offset: usize) -> (usize, usize) {
+    let before = &s[..offset];
+    let line = 1 + before.matches('\n').count();
+    let col = 1 + match before.rfind('\n') {
+        Some(idx) => before[idx + 1..].chars().count(),
+        None => before.chars().count(),
+    };
+    (line, col)
+}
+
+/// Byte offset of `inner` into `outer`, which must contain it to get a meaningful location.
+fn offset(outer: &str, inner: &str) -> usize {
+    let start = outer.as_ptr() as usize;
+    let at = inner.as_ptr() as usize;
+    if (start..=start + outer.len()).contains(&at) {
+        at - start
+    } else {
+        0
+    }
+}
+
+impl Error {
+    /// Moves an error raised while parsing `inner`, a slice of `outer`, to its location in `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let (line, col) = locate(outer, offset(outer, inner));
+        if self.line == 1 {
+            self.col += col - 1;
+        }
+        self.line += line - 1;
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day{:02}: line {}, col {}: {}",
+            self.day, self.line, self.col, self.msg
+        )
+    }
+}
+
+impl error::Error for Error {}
+
+/// Helpers for parsing the input of a day, or a slice of it, into located errors.
+///
+/// Slices passed to a `Parser` must be taken from the string it was created with.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    day: u32,
+    s: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(day: u32, s: &'a str) -> Self {
+        Parser { day, s }
+    }
+
+    /// An error at the start of `at`.
+    pub fn error(&self, at: &str, msg: impl Into<String>) -> Error {
+        let (line, col) = locate(self.s, offset(self.s, at));
+        Error {
+            day: self.day,
+            line,
+            col,
+            msg: msg.into(),
+        }
+    }
+
+    /// An error right after `s`, for when something is missing.
+    pub fn error_after(&self, s: &str, msg: impl Into<String>) -> Error {
+        self.error(&s[s.len()..], msg)
+    }
+
+    /// Parses `token` as a number.
+    pub fn num<T: FromStr>(&self, token: &str) -> Result<T, Error> {
+        token
+            .parse()
+            .map_err(|_| self.error(token, "expected number"))
+    }
+
+    /// Splits `s` at the first `sep`.
+    pub fn split_once(&self, s: &'a str, sep: &str) -> Result<(&'a str, &'a str), Error> {
+        s.split_once(sep)
+            .ok_or_else(|| self.error_after(s, format!("expected '{}'", sep)))
+    }
+
+    /// Strips `prefix` off `s`.
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, Error> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected '{}'", prefix)))
+    }
+
+    /// Strips `suffix` off `s`.
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, Error> {
+        s.strip_suffix(suffix)
+            .ok_or_else(|| self.error_after(s, format!("expected '{}'", suffix)))
+    }
+
+    /// Parses `item`, a slice of the input, locating its errors in the input.
+    pub fn parse<T: FromStr<Err = Error>>(&self, item: &str) -> Result<T, Error> {
+        item.parse().map_err(|err: Error| err.within(self.s, item))
+    }
+
+    /// Parses each of `items`, slices of the input.
+    pub fn each<T, I>(&self, items: I) -> Result<Vec<T>, Error>
+    where
+        T: FromStr<Err = Error>,
+        I: IntoIterator<Item = &'a str>,
+    {
+        items.into_iter().map(|item| self.parse(item)).collect()
+    }
+
+    /// Parses each line of the input.
+    pub fn lines<T: FromStr<Err = Error>>(&self) -> Result<Vec<T>, Error> {
+        self.each(self.s.lines())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn located() {
+        let input = "1,2\n3,x\n";
+        let p = Parser::new(19, input);
+        let line = input.lines().nth(1).unwrap();
+        let err = p.num::<i32>(&line[2..]).unwrap_err();
+        assert_eq!((err.line, err.col), (2, 3));
+        assert_eq!(err.to_string(), "day19: line 2, col 3: expected number");
+
+        // errors from parsing a single line are moved to where the line is
+        let err = Parser::new(19, line).error(&line[2..], "nope");
+        assert_eq!(
+            err.within(input, line).to_string(),
+            "day19: line 2, col 3: nope"
+        );
+
+        let err = p.split_once(line, ";").unwrap_err();
+        assert_eq!((err.line, err.col), (2, 4));
+    }
+}
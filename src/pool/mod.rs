@@ -0,0 +1,75 @@
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// Runs `work` on each of `tasks` on up to `jobs` threads, passing each result to `done` on the
+/// calling thread in the order of the tasks, as soon as it and those before it are finished.
+pub fn run<T, R, W, D>(jobs: usize, tasks: &[T], work: W, mut done: D)
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    D: FnMut(&T, R),
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let (next, work, tx) = (&next, &work, tx.clone());
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(task) = tasks.get(idx) else {
+                    break;
+                };
+                if tx.send((idx, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        // results finished ahead of an earlier task wait here for their turn
+        let mut pending: Vec<Option<R>> = tasks.iter().map(|_| None).collect();
+        let mut turn = 0;
+        for (idx, result) in rx {
+            pending[idx] = Some(result);
+            while let Some(result) = pending.get_mut(turn).and_then(Option::take) {
+                done(&tasks[turn], result);
+                turn += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pool() {
+        let tasks: Vec<u64> = (0..20).rev().collect();
+        for jobs in [1, 4, 100] {
+            let mut results = Vec::new();
+            run(
+                jobs,
+                &tasks,
+                |n| {
+                    // later tasks finish first
+                    thread::sleep(std::time::Duration::from_millis(*n));
+                    n * n
+                },
+                |n, square| results.push((*n, square)),
+            );
+            assert_eq!(
+                results,
+                tasks.iter().map(|n| (*n, n * n)).collect::<Vec<_>>()
+            );
+        }
+
+        run(4, &[] as &[u64], |n| *n, |_, _| panic!("no tasks"));
+    }
+}
@@ -0,0 +1,172 @@
+use std::ops::RangeInclusive;
+
+/// Integers [`Rng::range`] can draw.
+pub trait Int: Copy {
+    fn to_i128(self) -> i128;
+
+    fn from_i128(n: i128) -> Self;
+}
+
+macro_rules! int {
+    ($($ty:ty),*) => {
+        $(impl Int for $ty {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(n: i128) -> Self {
+                n as $ty
+            }
+        })*
+    };
+}
+
+int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A small pseudo-random number generator (SplitMix64), seeded so that what is drawn from it can
+/// be reproduced.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range<T: Int>(&mut self, range: RangeInclusive<T>) -> T {
+        let (from, to) = (range.start().to_i128(), range.end().to_i128());
+        assert!(from <= to, "empty range");
+        // the bias of the modulo is negligible for the ranges drawn from
+        let offset = u128::from(self.next_u64()) % (to - from + 1) as u128;
+        T::from_i128(from + offset as i128)
+    }
+
+    /// Whether an event of probability `p` happens.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.range(0..=idx));
+        }
+    }
+}
+
+/// Checks that `solution` solves small inputs it generates, for a few seeds, panicking on the
+/// first that it fails to solve. The inputs are generated for the parameters of the day's first
+/// example, which scale the puzzle down as the example does.
+#[cfg(test)]
+pub fn assert_generated(solution: &dyn crate::Solution) {
+    use crate::{answers::solve, examples};
+
+    let dir = format!("{}/{}", env!("CARGO_MANIFEST_DIR"), examples::DIR);
+    let examples = examples::load(&dir, solution.year(), solution.day()).unwrap();
+    let params = examples
+        .into_iter()
+        .next()
+        .map(|example| example.params)
+        .unwrap_or_default();
+    for seed in 0..3 {
+        let Some(input) = solution.generate(&mut Rng::new(seed), 12, &params) else {
+            return;
+        };
+        let input = input.unwrap();
+        for part in 1..=2 {
+            if let Some(Err(err)) = solve(solution, &input, &params, part) {
+                panic!(
+                    "day {} part {} with seed {}: {}\n{}",
+                    solution.day(),
+                    part,
+                    seed,
+                    err,
+                    input
+                );
+            }
+        }
+    }
+}
+
+/// Checks that `optimised` agrees with `reference` on values drawn by `generate`, such as inputs
+/// along with parameters, for a few seeds at each size in `sizes`, panicking on the first value
+/// they disagree on. Smaller sizes are tried first, so that value is about as small as one they
+/// disagree on can be.
+#[cfg(test)]
+pub fn assert_agree<T, A>(
+    sizes: RangeInclusive<usize>,
+    generate: impl Fn(&mut Rng, usize) -> T,
+    optimised: impl Fn(&T) -> crate::Result<A>,
+    reference: impl Fn(&T) -> A,
+) where
+    T: std::fmt::Debug,
+    A: PartialEq + std::fmt::Debug,
+{
+    for size in sizes {
+        for seed in 0..3 {
+            let value = generate(&mut Rng::new(seed), size);
+            let expected = reference(&value);
+            match optimised(&value) {
+                Ok(answer) if answer == expected => (),
+                answer => panic!(
+                    "size {} with seed {}: got {:?}, expected {:?}\n{:?}",
+                    size, seed, answer, expected, value
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng() {
+        let draw = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..100).map(|_| rng.range(-3..=3)).collect::<Vec<i32>>()
+        };
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+        assert!(draw(7).iter().all(|n| (-3..=3).contains(n)));
+        assert!((-3..=3).all(|n| draw(7).contains(&n)));
+
+        let mut rng = Rng::new(1);
+        assert_eq!(rng.range(u64::MAX..=u64::MAX), u64::MAX);
+        rng.range(0..=u64::MAX);
+        assert!(!rng.chance(0.0) && rng.chance(1.0));
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn agree() {
+        let sum = |n: &u32| (1..=*n).sum::<u32>();
+        assert_agree(0..=20, |rng, size| rng.range(0..=size as u32), |n| Ok(n * (n + 1) / 2), sum);
+
+        let result = std::panic::catch_unwind(|| {
+            assert_agree(0..=20, |_, size| size as u32, |n| Ok(n * n / 2), sum);
+        });
+        let err = result.unwrap_err();
+        let msg = err.downcast_ref::<String>().unwrap();
+        assert!(msg.starts_with("size 1 with seed 0: got Ok(0), expected 1"), "{}", msg);
+    }
+}
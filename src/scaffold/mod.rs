@@ -0,0 +1,187 @@
+use std::{fs, io, path::Path};
+
+use aoc::{Error, Result};
+
+const LIB: &str = "src/lib.rs";
+
+/// The module of a new year, `{year}` being the year.
+const YEAR_TEMPLATE: &str = r#"//! Solutions to [Advent of Code {year}](https://adventofcode.com/{year}/).
+
+pub const YEAR: u32 = {year};
+
+// to add a new day, run `aoc {year} new <day>` to create `src/y{year}/dayNN/mod.rs` and list it here,
+// then record its answers in `answers.json` and its examples in `examples/{year}/dayNN-*.txt`
+days! {
+}
+"#;
+
+/// The module of a new day, `{day}` being its number and `{DD}` its zero-padded number.
+const TEMPLATE: &str = r#"use crate::parse::{self, Parser};
+
+const DAY: u32 = {day};
+
+fn parse(input: &str) -> Result<Vec<&str>, parse::Error> {
+    let p = Parser::new(DAY, input);
+    if input.is_empty() {
+        return Err(p.error(input, "expected input"));
+    }
+    Ok(input.lines().collect())
+}
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    let _lines = parse(input)?;
+    Err(crate::Error::solve(DAY, "not solved yet"))
+}
+
+pub fn part2(input: &str) -> crate::Result<usize> {
+    let _lines = parse(input)?;
+    Err(crate::Error::solve(DAY, "not solved yet"))
+}
+
+pub struct Day{DD};
+
+impl crate::Solution for Day{DD} {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "{name}"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+}
+"#;
+
+fn render(day: u32, name: &str) -> String {
+    TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{DD}", &format!("{:02}", day))
+        .replace("{name}", &name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Adds `entry` to the invocation of the `list` macro in `module`, such as the `days!` list of a
+/// year, keeping it sorted. `None` if there is no such list.
+fn register(module: &str, list: &str, entry: &str) -> Option<String> {
+    let open = format!("{}! {{\n", list);
+    let start = module.find(&open)? + open.len();
+    let end = start + module[start..].find("}\n")?;
+
+    let entry = format!("    {},", entry);
+    let mut entries: Vec<&str> = module[start..end].lines().collect();
+    entries.push(&entry);
+    entries.sort();
+    entries.dedup();
+    Some(format!(
+        "{}{}\n{}",
+        &module[..start],
+        entries.join("\n"),
+        &module[end..]
+    ))
+}
+
+/// Reads the module at `path` and adds `entry` to its `list`.
+fn registered(path: &str, module: io::Result<String>, list: &str, entry: &str) -> Result<String> {
+    let module = module.map_err(|err| Error::io(path, err))?;
+    register(&module, list, entry)
+        .ok_or_else(|| Error::data(path, format!("expected a {}! list", list)))
+}
+
+/// Sets up a new day of `year`: its module with stubs, registered in the year's module, along
+/// with empty input and example files, the latter being tested once it is filled in with an
+/// example and its answers. The year's module is created and registered in the library on its
+/// first day. Nothing is written if any of the files exists.
+pub fn new(year: u32, day: u32, name: &str) -> Result<()> {
+    let year_module = format!("src/y{}/mod.rs", year);
+    let module = format!("src/y{}/day{:02}/mod.rs", year, day);
+    let input = format!("input/{}/day{:02}", year, day);
+    let example = format!("examples/{}/day{:02}-1.txt", year, day);
+
+    for path in [&module, &input, &example] {
+        if Path::new(path).exists() {
+            return Err(Error::io(path, io::ErrorKind::AlreadyExists.into()));
+        }
+    }
+
+    let (days, lib) = if Path::new(&year_module).exists() {
+        (fs::read_to_string(&year_module), None)
+    } else {
+        let entry = format!("y{}", year);
+        let lib = registered(LIB, fs::read_to_string(LIB), "years", &entry)?;
+        (
+            Ok(YEAR_TEMPLATE.replace("{year}", &year.to_string())),
+            Some(lib),
+        )
+    };
+    let entry = format!("day{:02}::Day{:02}", day, day);
+    let days = registered(&year_module, days, "days", &entry)?;
+
+    for (path, contents) in [
+        (&module, render(day, name)),
+        (&input, String::new()),
+        (&example, String::new()),
+    ] {
+        if let Some(dir) = Path::new(path).parent() {
+            fs::create_dir_all(dir).map_err(|err| Error::io(dir.to_string_lossy(), err))?;
+        }
+        fs::write(path, contents).map_err(|err| Error::io(path, err))?;
+        println!("Created '{}'", path);
+    }
+    fs::write(&year_module, days).map_err(|err| Error::io(&year_module, err))?;
+    println!("Registered day {} in '{}'", day, year_module);
+    if let Some(lib) = lib {
+        fs::write(LIB, lib).map_err(|err| Error::io(LIB, err))?;
+        println!("Registered year {} in '{}'", year, LIB);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaffold() {
+        let year = "days! {\n    day01::Day01,\n    day03::Day03,\n}\n";
+        assert_eq!(
+            register(year, "days", "day02::Day02").unwrap(),
+            "days! {\n    day01::Day01,\n    day02::Day02,\n    day03::Day03,\n}\n"
+        );
+        assert_eq!(register(year, "days", "day03::Day03").unwrap(), year);
+        assert_eq!(register(year, "years", "y2023"), None);
+        assert_eq!(
+            register("years! {\n    y2022,\n}\n", "years", "y2015").unwrap(),
+            "years! {\n    y2015,\n    y2022,\n}\n"
+        );
+
+        let empty = YEAR_TEMPLATE.replace("{year}", "2015");
+        assert!(empty.contains("pub const YEAR: u32 = 2015;"));
+        assert!(register(&empty, "days", "day01::Day01")
+            .unwrap()
+            .ends_with("days! {\n    day01::Day01,\n}\n"));
+
+        let module = render(7, "No \"Space\"");
+        assert!(module.contains("const DAY: u32 = 7;"));
+        assert!(module.contains("impl crate::Solution for Day07 {"));
+        assert!(module.contains(r#""No \"Space\"""#));
+    }
+}
@@ -0,0 +1,196 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Costs of moving between states, such as the number of steps.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<T: Copy + Ord + Default + Add<Output = T>> Cost for T {}
+
+/// The result of a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome<S, C> {
+    /// The cost of the cheapest path to a goal, along with the states on it from the start to the
+    /// goal, both included. `None` if no goal can be reached.
+    pub found: Option<(C, Vec<S>)>,
+    /// The number of states whose successors were generated.
+    pub expanded: usize,
+}
+
+impl<S, C: Copy> Outcome<S, C> {
+    pub fn cost(&self) -> Option<C> {
+        self.found.as_ref().map(|(cost, _)| *cost)
+    }
+
+    pub fn path(&self) -> Option<&[S]> {
+        self.found.as_ref().map(|(_, path)| &path[..])
+    }
+}
+
+/// States seen so far, each with the index of the state it was reached from and its cost.
+struct Seen<S, C> {
+    nodes: Vec<(S, usize, C)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Seen<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Seen {
+            nodes: vec![(start.clone(), usize::MAX, cost)],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Records reaching `state` from the state at `parent`, returning its index unless it was
+    /// reached as cheaply before.
+    fn reach(&mut self, state: S, parent: usize, cost: C) -> Option<usize> {
+        match self.index.entry(state) {
+            Entry::Occupied(entry) => {
+                let node = &mut self.nodes[*entry.get()];
+                if cost >= node.2 {
+                    return None;
+                }
+                node.1 = parent;
+                node.2 = cost;
+                Some(*entry.get())
+            }
+            Entry::Vacant(entry) => {
+                self.nodes.push((entry.key().clone(), parent, cost));
+                Some(*entry.insert(self.nodes.len() - 1))
+            }
+        }
+    }
+
+    fn found(&self, mut idx: usize, expanded: usize) -> Outcome<S, C> {
+        let cost = self.nodes[idx].2;
+        let mut path = Vec::new();
+        while idx != usize::MAX {
+            path.push(self.nodes[idx].0.clone());
+            idx = self.nodes[idx].1;
+        }
+        path.reverse();
+        Outcome {
+            found: Some((cost, path)),
+            expanded,
+        }
+    }
+}
+
+/// Breadth first search from `start` for a state for which `goal` holds, each step costing 1.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Outcome<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = Seen::new(start, 0);
+    let mut frontier = VecDeque::from([0]);
+    let mut expanded = 0;
+    while let Some(idx) = frontier.pop_front() {
+        let (state, _, cost) = &seen.nodes[idx];
+        if goal(state) {
+            return seen.found(idx, expanded);
+        }
+
+        expanded += 1;
+        let cost = cost + 1;
+        for next in successors(state) {
+            // the first path to reach a state is the shortest
+            if !seen.index.contains_key(&next) {
+                frontier.extend(seen.reach(next, idx, cost));
+            }
+        }
+    }
+    Outcome {
+        found: None,
+        expanded,
+    }
+}
+
+/// Dijkstra's search from `start` for a state for which `goal` holds. `successors` yields the
+/// states reachable from a state along with the cost of getting there.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Outcome<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    a_star(start, successors, |_| C::default(), goal)
+}
+
+/// A* search from `start` for a state for which `goal` holds, guided by `heuristic`, which must
+/// never overestimate the remaining cost to a goal for the found path to be the cheapest.
+pub fn a_star<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Outcome<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut seen = Seen::new(start, C::default());
+    let mut expanded = 0;
+    while let Some(Reverse((_, cost, idx))) = frontier.pop() {
+        let state = &seen.nodes[idx].0;
+        if cost > seen.nodes[idx].2 {
+            // reached more cheaply since being queued
+            continue;
+        }
+        if goal(state) {
+            return seen.found(idx, expanded);
+        }
+
+        expanded += 1;
+        for (next, step) in successors(state) {
+            let cost = cost + step;
+            if let Some(next) = seen.reach(next, idx, cost) {
+                frontier.push(Reverse((cost + heuristic(&seen.nodes[next].0), cost, next)));
+            }
+        }
+    }
+    Outcome {
+        found: None,
+        expanded,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search() {
+        // a line of states where going right costs 1 and skipping one costs 3
+        let successors = |n: &i32| [(n + 1, 1), (n + 2, 3)];
+
+        let outcome = dijkstra(0, successors, |n| *n == 4);
+        assert_eq!(outcome.cost(), Some(4));
+        assert_eq!(outcome.path(), Some(&[0, 1, 2, 3, 4][..]));
+        assert_eq!(outcome.expanded, 4);
+
+        let outcome = a_star(0, successors, |n| 4 - n, |n| *n == 4);
+        assert_eq!(outcome.cost(), Some(4));
+        assert!(outcome.expanded <= 4);
+
+        let outcome = bfs(0, |n| [n + 1, n + 2], |n| *n == 4);
+        assert_eq!(outcome.path(), Some(&[0, 2, 4][..]));
+
+        let outcome = bfs(0, |n| (*n < 3).then_some(n + 1), |n| *n == 4);
+        assert_eq!(outcome.found, None);
+        assert_eq!(outcome.expanded, 4);
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fmt, hint};
+
+use crate::{
+    params::{self, Overrides},
+    random::Rng,
+    visualize::Frames,
+};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Int(n.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Int(n)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        i64::try_from(n).map_or_else(|_| Answer::Text(n.to_string()), Answer::Int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// A day's puzzle, as registered in the `days!` list of its year.
+pub trait Solution: Sync {
+    fn year(&self) -> u32;
+
+    fn day(&self) -> u32;
+
+    fn name(&self) -> &'static str;
+
+    /// Only parses `input`, `None` if the day has no separate parsing step.
+    fn parse(&self, _input: &str) -> Option<crate::Result<()>> {
+        None
+    }
+
+    /// The name and value of each of the day's parameters with `overrides` applied, failing if
+    /// any of them is not a parameter of the day or not a valid value. None by default.
+    fn params(&self, overrides: &Overrides) -> crate::Result<Vec<(&'static str, String)>> {
+        match overrides.iter().next() {
+            Some((name, _)) => Err(params::unknown(self.day(), name)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn part1(&self, input: &str, params: &Overrides) -> crate::Result<Answer>;
+
+    /// `None` if the day has no second part.
+    fn part2(&self, input: &str, params: &Overrides) -> Option<crate::Result<Answer>>;
+
+    /// Runs `part` like `part1` or `part2`, drawing the frames of its simulation to `frames`.
+    /// `None` if the part is not visualized, which is the default.
+    fn visualize(
+        &self,
+        _input: &str,
+        _params: &Overrides,
+        _part: u32,
+        _frames: &mut Frames,
+    ) -> Option<crate::Result<Answer>> {
+        None
+    }
+
+    /// A random puzzle input drawn from `rng`, whose size grows with `size`, such as the number of
+    /// lines, and which can be solved with `params`. `None` if the day has no generator, which is
+    /// the default.
+    fn generate(
+        &self,
+        _rng: &mut Rng,
+        _size: usize,
+        _params: &Overrides,
+    ) -> Option<crate::Result<String>> {
+        None
+    }
+}
+
+/// Wraps the result of a day's parser for `Solution::parse`, keeping the parsed value from being
+/// optimized away.
+pub fn parsed<T, E>(parsed: Result<T, E>) -> Option<crate::Result<()>>
+where
+    E: Into<crate::Error>,
+{
+    Some(
+        parsed
+            .map(|parsed| drop(hint::black_box(parsed)))
+            .map_err(Into::into),
+    )
+}
+
+/// The year days are looked up in when none is given.
+pub const DEFAULT_YEAR: u32 = 2022;
+
+/// The years with solutions, in order.
+pub fn years() -> impl Iterator<Item = u32> {
+    crate::YEARS.iter().map(|(year, _)| *year)
+}
+
+/// The days of `year`, none if there is no such year.
+pub fn all(year: u32) -> &'static [&'static dyn Solution] {
+    crate::YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map_or(&[], |(_, days)| days)
+}
+
+pub fn get(year: u32, day: u32) -> Option<&'static dyn Solution> {
+    all(year)
+        .iter()
+        .copied()
+        .find(|solution| solution.day() == day)
+}
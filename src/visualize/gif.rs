@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+/// The number of bits of a palette index, the palette having `1 << BITS` colours.
+pub const BITS: u8 = 4;
+
+/// The largest number of codes in the LZW table.
+const CODES: u16 = 4096;
+
+/// Packs codes of varying sizes into bytes, least significant bit first.
+#[derive(Debug, Default)]
+struct Bits {
+    bytes: Vec<u8>,
+    acc: u32,
+    len: u8,
+}
+
+impl Bits {
+    fn write(&mut self, code: u16, size: u8) {
+        self.acc |= u32::from(code) << self.len;
+        self.len += size;
+        while self.len >= 8 {
+            self.bytes.push(self.acc as u8);
+            self.acc >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.acc as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Compresses `pixels`, each an index into a palette of `1 << BITS` colours, with the variant of
+/// LZW used by GIF.
+fn lzw(pixels: &[u8]) -> Vec<u8> {
+    let clear = 1 << BITS;
+    let end = clear + 1;
+    let mut bits = Bits::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut size = BITS + 1;
+    let mut next = end + 1;
+
+    bits.write(clear, size);
+    let mut pixels = pixels.iter().copied();
+    if let Some(first) = pixels.next() {
+        let mut prefix = u16::from(first);
+        for pixel in pixels {
+            if let Some(code) = table.get(&(prefix, pixel)) {
+                prefix = *code;
+                continue;
+            }
+
+            bits.write(prefix, size);
+            if next == CODES {
+                bits.write(clear, size);
+                table.clear();
+                size = BITS + 1;
+                next = end + 1;
+            } else {
+                table.insert((prefix, pixel), next);
+                next += 1;
+                // the decoder adds its entries one code later, and grows its codes as soon as
+                // the next entry would not fit
+                if next - 1 == 1 << size && size < 12 {
+                    size += 1;
+                }
+            }
+            prefix = u16::from(pixel);
+        }
+        bits.write(prefix, size);
+    }
+    bits.write(end, size);
+    bits.finish()
+}
+
+/// Appends one image of `width` by `height` `pixels` to `out`, shown for `delay` hundredths of a
+/// second and cleared before the next one.
+pub fn image(out: &mut Vec<u8>, width: u16, height: u16, delay: u16, pixels: &[u8]) {
+    // graphic control extension: restore to the background afterwards, no transparency
+    out.extend([0x21, 0xf9, 4, 2 << 2]);
+    out.extend(delay.to_le_bytes());
+    out.extend([0, 0]);
+
+    out.push(0x2c);
+    out.extend([0, 0, 0, 0]);
+    out.extend(width.to_le_bytes());
+    out.extend(height.to_le_bytes());
+    out.push(0);
+
+    out.push(BITS);
+    for block in lzw(pixels).chunks(255) {
+        out.push(block.len() as u8);
+        out.extend(block);
+    }
+    out.push(0);
+}
+
+/// An animated GIF of `width` by `height` looping forever over `images`, as appended by
+/// [`image`], in the colours of `palette`.
+pub fn file(width: u16, height: u16, palette: &[[u8; 3]; 1 << BITS], images: &[u8]) -> Vec<u8> {
+    let mut out = b"GIF89a".to_vec();
+    out.extend(width.to_le_bytes());
+    out.extend(height.to_le_bytes());
+    // a global palette of 1 << BITS colours, the first being the background
+    out.extend([0x80 | (BITS - 1) << 4 | (BITS - 1), 0, 0]);
+    out.extend(palette.iter().flatten());
+
+    out.extend([0x21, 0xff, 11]);
+    out.extend(b"NETSCAPE2.0");
+    out.extend([3, 1, 0, 0, 0]);
+
+    out.extend(images);
+    out.push(0x3b);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decompresses codes as a GIF decoder does.
+    fn decode(bytes: &[u8]) -> Vec<u8> {
+        let (clear, end) = (1 << BITS, (1 << BITS) + 1);
+        let reset = || -> Vec<Vec<u8>> { (0..=end).map(|c| vec![c as u8]).collect() };
+        let mut table = reset();
+        let mut size = BITS + 1;
+        let mut prev: Option<usize> = None;
+        let mut out = Vec::new();
+
+        let (mut acc, mut len, mut bytes) = (0u32, 0, bytes.iter());
+        loop {
+            while len < size {
+                acc |= u32::from(*bytes.next().expect("no end code")) << len;
+                len += 8;
+            }
+            let code = (acc & ((1 << size) - 1)) as usize;
+            acc >>= size;
+            len -= size;
+
+            if code == clear {
+                table = reset();
+                size = BITS + 1;
+                prev = None;
+                continue;
+            } else if code == end {
+                return out;
+            }
+
+            let entry = match (table.get(code), prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) if code == table.len() => {
+                    let mut entry = table[prev].clone();
+                    entry.push(entry[0]);
+                    entry
+                }
+                _ => panic!("invalid code {}", code),
+            };
+            out.extend(&entry);
+            if let Some(prev) = prev {
+                if table.len() < usize::from(CODES) {
+                    let mut added = table[prev].clone();
+                    added.push(entry[0]);
+                    table.push(added);
+                    if table.len() == 1 << size && size < 12 {
+                        size += 1;
+                    }
+                }
+            }
+            prev = Some(code);
+        }
+    }
+
+    #[test]
+    fn gif() {
+        assert_eq!(decode(&lzw(&[])), []);
+        for pixels in [
+            vec![3],
+            vec![1, 1, 1, 1, 1, 1, 1],
+            (0..20_000).map(|n| (n * n / 7 % 16) as u8).collect(),
+            (0..50_000).map(|n| (n / 1000 % 3) as u8).collect(),
+        ] {
+            assert_eq!(decode(&lzw(&pixels)), pixels);
+        }
+
+        let mut images = Vec::new();
+        image(&mut images, 2, 1, 5, &[0, 1]);
+        let file = file(2, 1, &[[0; 3]; 16], &images);
+        assert!(file.starts_with(b"GIF89a\x02\x00\x01\x00\xb3"));
+        assert!(file.ends_with(&[0, 0x3b]));
+    }
+}
@@ -0,0 +1,300 @@
+mod gif;
+
+use std::{
+    fs,
+    io::{self, Write},
+    iter,
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use crate::grid::Grid;
+
+/// The colours of the cells of frames written as images, indexed by [`colour`].
+const PALETTE: [[u8; 3]; 1 << gif::BITS] = [
+    [0, 0, 0],
+    [40, 42, 54],
+    [200, 200, 200],
+    [230, 190, 90],
+    [230, 60, 60],
+    [80, 200, 120],
+    [90, 140, 230],
+    [255, 255, 255],
+    [240, 130, 50],
+    [190, 110, 220],
+    [60, 200, 200],
+    [220, 220, 80],
+    [150, 90, 60],
+    [120, 120, 140],
+    [240, 150, 180],
+    [110, 170, 90],
+];
+
+/// The colour of a cell drawn as `c`: fixed ones for the characters most days draw with, such as
+/// `#` for rock, others picked by character code.
+fn colour(c: char) -> u8 {
+    match c {
+        ' ' => 0,
+        '.' => 1,
+        '#' => 2,
+        'o' => 3,
+        '@' | 'H' => 4,
+        'E' => 5,
+        '>' | '<' | '^' | 'v' => 6,
+        _ => 7 + (c as u32 % 9) as u8,
+    }
+}
+
+/// The size in pixels the longer side of an image is scaled up to, if its cells are few.
+const MIN_SIZE: usize = 320;
+
+/// The size in pixels of the cells of an image of `frame`.
+fn scale(frame: &Grid<char>) -> usize {
+    (MIN_SIZE / frame.width().max(frame.height()).max(1)).max(1)
+}
+
+/// The colour of each pixel of `frame`, row by row, each cell being `scale` pixels wide and high.
+fn pixels(frame: &Grid<char>, scale: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(frame.width() * frame.height() * scale * scale);
+    for row in frame.rows() {
+        let row: Vec<u8> = row
+            .iter()
+            .flat_map(|c| iter::repeat_n(colour(*c), scale))
+            .collect();
+        for _ in 0..scale {
+            pixels.extend(&row);
+        }
+    }
+    pixels
+}
+
+/// Receives the frames of a simulation, each a grid of characters such as the puzzle descriptions
+/// draw them with.
+pub trait Sink {
+    /// Shows or stores `frame`. Failures are kept until [`Sink::finish`] so simulations need not
+    /// handle them, later frames being dropped.
+    fn frame(&mut self, frame: &Grid<char>);
+
+    /// Completes the output once the simulation is done, returning the number of frames or the
+    /// first failure.
+    fn finish(&mut self) -> crate::Result<usize>;
+}
+
+/// The frames of a simulation, drawn only if they are passed to a sink.
+pub struct Frames<'a> {
+    sink: Option<&'a mut dyn Sink>,
+}
+
+impl<'a> Frames<'a> {
+    /// Frames that are never drawn, when not visualizing.
+    pub fn off() -> Self {
+        Frames { sink: None }
+    }
+
+    pub fn new(sink: &'a mut dyn Sink) -> Self {
+        Frames { sink: Some(sink) }
+    }
+
+    /// Passes the frame drawn by `draw` to the sink, without drawing it if there is none.
+    pub fn draw(&mut self, draw: impl FnOnce() -> Grid<char>) {
+        if let Some(sink) = &mut self.sink {
+            sink.frame(&draw());
+        }
+    }
+}
+
+/// Animates frames in the terminal, redrawing them in place on stdout.
+#[derive(Debug)]
+pub struct Ascii {
+    delay: Duration,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+impl Ascii {
+    /// Shows each frame for `delay`.
+    pub fn new(delay: Duration) -> Self {
+        Ascii {
+            delay,
+            frames: 0,
+            error: None,
+        }
+    }
+}
+
+impl Sink for Ascii {
+    fn frame(&mut self, frame: &Grid<char>) {
+        if self.error.is_some() {
+            return;
+        }
+        // clear the screen once, then move to the top left and clear what is left below
+        let clear = if self.frames == 0 { "\x1b[2J" } else { "" };
+        let written = write!(io::stdout(), "{}\x1b[H{}\n\x1b[J", clear, frame)
+            .and_then(|()| io::stdout().flush());
+        self.frames += 1;
+        match written {
+            Ok(()) => thread::sleep(self.delay),
+            Err(err) => self.error = Some(err),
+        }
+    }
+
+    fn finish(&mut self) -> crate::Result<usize> {
+        match self.error.take() {
+            Some(err) => Err(crate::Error::io("stdout", err)),
+            None => Ok(self.frames),
+        }
+    }
+}
+
+/// Writes each frame as a numbered PPM image in a directory.
+#[derive(Debug)]
+pub struct Ppm {
+    dir: String,
+    frames: usize,
+    error: Option<crate::Error>,
+}
+
+impl Ppm {
+    /// Writes to `dir`, which is created along with the first frame.
+    pub fn new(dir: &str) -> Self {
+        Ppm {
+            dir: dir.to_string(),
+            frames: 0,
+            error: None,
+        }
+    }
+}
+
+impl Sink for Ppm {
+    fn frame(&mut self, frame: &Grid<char>) {
+        if self.error.is_some() {
+            return;
+        }
+        if self.frames == 0 {
+            if let Err(err) = fs::create_dir_all(&self.dir) {
+                self.error = Some(crate::Error::io(&self.dir, err));
+                return;
+            }
+        }
+
+        let scale = scale(frame);
+        let (width, height) = (frame.width() * scale, frame.height() * scale);
+        let mut image = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+        for pixel in pixels(frame, scale) {
+            image.extend(PALETTE[usize::from(pixel)]);
+        }
+
+        self.frames += 1;
+        let path = format!("{}/{:06}.ppm", self.dir, self.frames);
+        if let Err(err) = fs::write(&path, image) {
+            self.error = Some(crate::Error::io(path, err));
+        }
+    }
+
+    fn finish(&mut self) -> crate::Result<usize> {
+        match self.error.take() {
+            Some(err) => Err(err),
+            None => Ok(self.frames),
+        }
+    }
+}
+
+/// Collects frames into an animated GIF, written once all frames are known.
+#[derive(Debug)]
+pub struct Gif {
+    path: String,
+    /// The time each frame is shown for, in hundredths of a second.
+    delay: u16,
+    /// The size of cells in pixels, that of the first frame so all frames are drawn alike.
+    scale: Option<usize>,
+    width: usize,
+    height: usize,
+    images: Vec<u8>,
+    frames: usize,
+}
+
+impl Gif {
+    /// Writes to `path`, along with its directory, showing each frame for `delay`.
+    pub fn new(path: &str, delay: Duration) -> Self {
+        Gif {
+            path: path.to_string(),
+            delay: (delay.as_millis() / 10).clamp(1, u16::MAX.into()) as u16,
+            scale: None,
+            width: 0,
+            height: 0,
+            images: Vec::new(),
+            frames: 0,
+        }
+    }
+}
+
+impl Sink for Gif {
+    fn frame(&mut self, frame: &Grid<char>) {
+        let scale = *self.scale.get_or_insert_with(|| scale(frame));
+        let (width, height) = (frame.width() * scale, frame.height() * scale);
+        // larger images do not fit the format, and would not fit the screen either
+        if width > usize::from(u16::MAX) || height > usize::from(u16::MAX) {
+            return;
+        }
+
+        self.width = self.width.max(width);
+        self.height = self.height.max(height);
+        let pixels = pixels(frame, scale);
+        gif::image(
+            &mut self.images,
+            width as u16,
+            height as u16,
+            self.delay,
+            &pixels,
+        );
+        self.frames += 1;
+    }
+
+    fn finish(&mut self) -> crate::Result<usize> {
+        let file = gif::file(
+            self.width as u16,
+            self.height as u16,
+            &PALETTE,
+            &self.images,
+        );
+        if let Some(dir) = Path::new(&self.path).parent() {
+            fs::create_dir_all(dir).map_err(|err| crate::Error::io(dir.to_string_lossy(), err))?;
+        }
+        fs::write(&self.path, file).map_err(|err| crate::Error::io(&self.path, err))?;
+        Ok(self.frames)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Keeps the frames as text.
+    struct Text(Vec<String>);
+
+    impl Sink for Text {
+        fn frame(&mut self, frame: &Grid<char>) {
+            self.0.push(frame.to_string());
+        }
+
+        fn finish(&mut self) -> crate::Result<usize> {
+            Ok(self.0.len())
+        }
+    }
+
+    #[test]
+    fn visualize() {
+        let mut text = Text(Vec::new());
+        let mut frames = Frames::new(&mut text);
+        frames.draw(|| Grid::from_rows([vec!['#', '.']], ' '));
+        Frames::off().draw(|| panic!("drawn without a sink"));
+        assert_eq!(text.finish().unwrap(), 1);
+        assert_eq!(text.0, ["#."]);
+
+        let frame = Grid::from_rows([vec!['#', '.']], ' ');
+        assert_eq!(scale(&frame), MIN_SIZE / 2);
+        assert_eq!(pixels(&frame, 2), [2, 2, 1, 1, 2, 2, 1, 1]);
+        assert!((0..128u8).all(|c| usize::from(colour(c.into())) < PALETTE.len()));
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc::{examples, json, Error, Result};
+
+use crate::{format_time, input_path, Options};
+
+/// How often the watched files are checked for changes.
+const POLL: Duration = Duration::from_millis(500);
+
+/// A part run on one input, as `(input, part)`.
+type Key = (String, u32);
+
+/// The outcome of a part as reported by the runner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Outcome {
+    /// The answer, or the error the part failed with.
+    answer: std::result::Result<String, String>,
+    elapsed: Option<Duration>,
+    /// Whether the answer matches the expected one, when checking examples.
+    status: Option<String>,
+}
+
+/// The source files of `day`, its input and its examples, each with the time it was last
+/// modified, `None` if it does not exist.
+fn snapshot(year: u32, day: u32) -> Vec<(String, Option<SystemTime>)> {
+    let dir = format!("src/y{}/day{:02}", year, day);
+    let mut paths: Vec<String> = fs::read_dir(&dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path().to_string_lossy().into_owned())
+        .collect();
+    paths.sort();
+    paths.push(input_path(year, day));
+    paths.extend(examples::paths(examples::DIR, year, day).unwrap_or_default());
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Parses the JSON records printed by the runner, skipping those that are not about a part.
+fn parse(stdout: &str) -> Vec<(Key, Outcome)> {
+    let mut outcomes = Vec::new();
+    for record in stdout
+        .lines()
+        .filter_map(|line| line.parse::<json::Value>().ok())
+    {
+        let (Some(input), Some(part)) = (
+            record.get("input").and_then(json::Value::as_str),
+            record.get("part").and_then(json::Value::as_i64),
+        ) else {
+            continue;
+        };
+
+        let answer = match (record.get("answer"), record.get("error")) {
+            (Some(json::Value::Int(n)), _) => Ok(n.to_string()),
+            (Some(json::Value::String(s)), _) => Ok(s.clone()),
+            (_, Some(json::Value::String(err))) => Err(err.clone()),
+            _ => Err("no answer".to_string()),
+        };
+        let elapsed = record
+            .get("elapsed_ns")
+            .and_then(json::Value::as_i64)
+            .map(|ns| Duration::from_nanos(ns as u64));
+        let status = record
+            .get("status")
+            .and_then(json::Value::as_str)
+            .map(str::to_string);
+        outcomes.push((
+            (input.to_string(), part as u32),
+            Outcome {
+                answer,
+                elapsed,
+                status,
+            },
+        ));
+    }
+    outcomes
+}
+
+/// Rebuilds the runner and runs `day` as configured by `opts`, returning the outcome of each
+/// part. The output of the build and any errors go to stderr as they happen.
+fn run(year: u32, day: u32, opts: &Options) -> Result<Vec<(Key, Outcome)>> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut cmd = Command::new(&cargo);
+    cmd.args(["run", "--release", "--quiet", "--"])
+        .args([year.to_string(), day.to_string()])
+        .args(["--format", "json"]);
+    if opts.example {
+        cmd.arg("--example");
+    }
+    if let Some(part) = opts.part {
+        cmd.args(["--part".to_string(), part.to_string()]);
+    }
+    for (name, value) in opts.params.iter() {
+        cmd.arg("--param").arg(format!("{}={}", name, value));
+    }
+
+    let output = cmd
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|err| Error::io(cargo, err))?;
+    Ok(parse(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Describes how `outcome` differs from the `previous` one: the relative change in time, and the
+/// previous answer if it changed.
+fn change(outcome: &Outcome, previous: Option<&Outcome>) -> (String, Option<String>) {
+    let Some(previous) = previous else {
+        return (String::new(), None);
+    };
+
+    let time = match (outcome.elapsed, previous.elapsed) {
+        (Some(now), Some(before)) if !before.is_zero() => {
+            let change = (now.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+            format!("  {:+.1}%", change)
+        }
+        _ => String::new(),
+    };
+    let answer = (outcome.answer != previous.answer).then(|| match &previous.answer {
+        Ok(answer) => answer.clone(),
+        Err(err) => format!("error: {}", err),
+    });
+    (time, answer)
+}
+
+fn show((input, part): &Key, outcome: &Outcome, previous: Option<&Outcome>) {
+    let status = outcome
+        .status
+        .as_ref()
+        .map_or(String::new(), |status| format!("{:<7}  ", status));
+    let elapsed = outcome
+        .elapsed
+        .map_or(String::new(), |d| format!("  {} seconds", format_time(d)));
+    let (time, was) = change(outcome, previous);
+    println!("{}part {}  {}{}{}", status, part, input, elapsed, time);
+
+    let answer = match &outcome.answer {
+        Ok(answer) => answer.clone(),
+        Err(err) => format!("error: {}", err),
+    };
+    for (label, answer) in [("answer", Some(answer)), ("was", was)] {
+        match answer {
+            Some(answer) if answer.contains('\n') => {
+                println!("    {}:\n{}", label, answer.trim_end());
+            }
+            Some(answer) => println!("    {}: {}", label, answer),
+            None => (),
+        }
+    }
+}
+
+/// Runs `day` as configured by `opts` whenever its source, input or examples change, showing the
+/// outcome of each part along with how it differs from the previous run. Never returns unless the
+/// runner cannot be started.
+pub fn watch(year: u32, day: u32, opts: &Options) -> Result<()> {
+    let mut last = Vec::new();
+    let mut previous = HashMap::new();
+    loop {
+        let snapshot = snapshot(year, day);
+        if snapshot != last {
+            if !last.is_empty() {
+                // modified or created files, then removed ones
+                let removed = last
+                    .iter()
+                    .filter(|(path, _)| snapshot.iter().all(|(p, _)| p != path));
+                let changed: Vec<_> = snapshot
+                    .iter()
+                    .filter(|file| !last.contains(file))
+                    .chain(removed)
+                    .map(|(path, _)| path.as_str())
+                    .collect();
+                println!("Changed: {}", changed.join(", "));
+            }
+
+            let outcomes = run(year, day, opts)?;
+            if outcomes.is_empty() {
+                println!("No parts ran");
+            }
+            for (key, outcome) in &outcomes {
+                show(key, outcome, previous.get(key));
+            }
+            previous = outcomes.into_iter().collect();
+
+            println!("Watching {} files for changes...", snapshot.len());
+            last = snapshot;
+        }
+        thread::sleep(POLL);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watch() {
+        let stdout = concat!(
+            r#"{"year":2022,"day":9,"part":1,"answer":13,"elapsed_ns":2000,"input":"ex","error":null,"status":"PASS"}"#,
+            "\n",
+            r#"{"year":2022,"day":9,"part":2,"answer":null,"elapsed_ns":null,"input":"ex","error":"oops"}"#,
+            "\nError: oops\n",
+        );
+        let outcomes = parse(stdout);
+        assert_eq!(outcomes.len(), 2);
+        let (key, outcome) = &outcomes[0];
+        assert_eq!(key, &("ex".to_string(), 1));
+        assert_eq!(outcome.answer, Ok("13".to_string()));
+        assert_eq!(outcome.elapsed, Some(Duration::from_nanos(2000)));
+        assert_eq!(outcome.status.as_deref(), Some("PASS"));
+        assert_eq!(outcomes[1].1.answer, Err("oops".to_string()));
+
+        let faster = Outcome {
+            answer: Ok("14".to_string()),
+            elapsed: Some(Duration::from_nanos(1500)),
+            status: None,
+        };
+        assert_eq!(
+            change(&faster, Some(outcome)),
+            ("  -25.0%".to_string(), Some("13".to_string()))
+        );
+        assert_eq!(change(&faster, None), (String::new(), None));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 1;
+
+fn parse(input: &str) -> Result<Vec<i32>, parse::Error> {
+    let p = Parser::new(DAY, input);
+    input
+        .split("\n\n")
+        .map(|elf| elf.lines().map(|l| p.num::<i32>(l)).sum())
+        .collect()
+}
+
+pub fn part1(input: &str) -> crate::Result<i32> {
+    Ok(parse(input)?.into_iter().max().unwrap_or(0))
+}
+
+pub fn part2(input: &str) -> crate::Result<i32> {
+    let mut elves = parse(input)?;
+    elves.sort();
+    Ok(elves.iter().rev().take(3).sum())
+}
+
+/// A random input of `size` elves, each carrying a few items.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let items: Vec<String> = (0..rng.range(1..=8))
+                .map(|_| rng.range(1000..=20000).to_string())
+                .collect();
+            items.join("\n")
+        })
+        .collect();
+    elves.join("\n\n")
+}
+
+pub struct Day01;
+
+impl crate::Solution for Day01 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn name(&self) -> &'static str {
+        "Calorie Counting"
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
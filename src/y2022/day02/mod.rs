@@ -0,0 +1,94 @@
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 2;
+
+fn score(p: &Parser, round: &str) -> Result<i32, parse::Error> {
+    Ok(match round {
+        "A X" => 4,
+        "A Y" => 8,
+        "A Z" => 3,
+        "B X" => 1,
+        "B Y" => 5,
+        "B Z" => 9,
+        "C X" => 7,
+        "C Y" => 2,
+        "C Z" => 6,
+        _ => return Err(p.error(round, "expected round such as 'A X'")),
+    })
+}
+
+pub fn part1(input: &str) -> crate::Result<i32> {
+    let p = Parser::new(DAY, input);
+    input.lines().map(|round| score(&p, round)).sum::<Result<_, _>>().map_err(Into::into)
+}
+
+fn score_p2(p: &Parser, round: &str) -> Result<i32, parse::Error> {
+    Ok(match round {
+        "A X" => 3,
+        "A Y" => 4,
+        "A Z" => 8,
+        "B X" => 1,
+        "B Y" => 5,
+        "B Z" => 9,
+        "C X" => 2,
+        "C Y" => 6,
+        "C Z" => 7,
+        _ => return Err(p.error(round, "expected round such as 'A X'")),
+    })
+}
+
+pub fn part2(input: &str) -> crate::Result<i32> {
+    let p = Parser::new(DAY, input);
+    input.lines().map(|round| score_p2(&p, round)).sum::<Result<_, _>>().map_err(Into::into)
+}
+
+/// A random input of `size` rounds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rounds: Vec<String> = (0..size)
+        .map(|_| {
+            let (opponent, response) = (rng.pick(&['A', 'B', 'C']), rng.pick(&['X', 'Y', 'Z']));
+            format!("{} {}", opponent, response)
+        })
+        .collect();
+    rounds.join("\n")
+}
+
+pub struct Day02;
+
+impl crate::Solution for Day02 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use crate::{parse::Parser, random::Rng};
+
+const DAY: u32 = 3;
+
+fn priority(c: char) -> usize {
+    if c.is_ascii_lowercase() {
+        c as usize - 96
+    } else {
+        c as usize - 64 + 26
+    }
+}
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    let p = Parser::new(DAY, input);
+    let mut res = 0;
+    for line in input.lines() {
+        let compartment: HashSet<_> = line.chars().take(line.len() / 2).collect();
+        res += line
+            .chars()
+            .skip(line.len() / 2)
+            .find(|c| compartment.contains(c))
+            .map(priority)
+            .ok_or_else(|| p.error(line, "no item in both compartments"))?;
+    }
+    Ok(res)
+}
+
+pub fn part2(input: &str) -> crate::Result<usize> {
+    let p = Parser::new(DAY, input);
+    let mut res = 0;
+    for group in input.lines().collect::<Vec<_>>().chunks(3) {
+        res += group
+            .iter()
+            .map(|elf| elf.chars().collect::<HashSet<_>>())
+            .reduce(|a, b| a.intersection(&b).copied().collect())
+            .and_then(|set| set.into_iter().next())
+            .map(priority)
+            .ok_or_else(|| p.error(group[0], "no item common to the group"))?;
+    }
+    Ok(res)
+}
+
+/// Compartment of `len` items: the `required` ones and others drawn from `items`, in random
+/// order.
+fn compartment(rng: &mut Rng, required: &[char], items: &[char], len: usize) -> Vec<char> {
+    let mut compartment = required.to_vec();
+    while compartment.len() < len {
+        compartment.push(*rng.pick(items));
+    }
+    rng.shuffle(&mut compartment);
+    compartment
+}
+
+/// A random input of `size` groups of three elves. Each elf has exactly one item in both
+/// compartments, and each group exactly one item common to all three, as in the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut lines = Vec::new();
+    for _ in 0..size.max(1) {
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        // the other items are split between the elves so they only share the badge
+        for elf in items[1..].chunks(17) {
+            let mut elf = elf.to_vec();
+            elf.push(badge);
+            rng.shuffle(&mut elf);
+            let (shared, rest) = (elf[0], &elf[1..]);
+            let (first, second) = rest.split_at(rest.len() / 2);
+            let len = rng.range(4..=16);
+            let mut line = Vec::new();
+            for half in [first, second] {
+                let mut required = vec![shared];
+                required.extend(half.iter().filter(|item| **item == badge));
+                line.extend(compartment(rng, &required, half, len));
+            }
+            lines.push(line.into_iter().collect::<String>());
+        }
+    }
+    lines.join("\n")
+}
+
+pub struct Day03;
+
+impl crate::Solution for Day03 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,114 @@
+use std::str::FromStr;
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 4;
+
+struct Range {
+    from: i32,
+    to: i32,
+}
+
+impl FromStr for Range {
+    type Err = parse::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let (from, to) = p.split_once(s, "-")?;
+        Ok(Range {
+            from: p.num(from)?,
+            to: p.num(to)?,
+        })
+    }
+}
+
+struct Pair(Range, Range);
+
+impl Pair {
+    fn contained(&self) -> bool {
+        self.0.from <= self.1.from && self.0.to >= self.1.to
+            || self.1.from <= self.0.from && self.1.to >= self.0.to
+    }
+
+    fn overlaps(&self) -> bool {
+        self.0.to >= self.1.from && self.0.from <= self.1.to
+    }
+}
+
+impl FromStr for Pair {
+    type Err = parse::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let (first, second) = p.split_once(s, ",")?;
+        Ok(Pair(p.parse(first)?, p.parse(second)?))
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Pair>, parse::Error> {
+    Parser::new(DAY, input).lines()
+}
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    Ok(parse(input)?.iter().filter(|pair| pair.contained()).count())
+}
+
+pub fn part2(input: &str) -> crate::Result<usize> {
+    Ok(parse(input)?.iter().filter(|pair| pair.overlaps()).count())
+}
+
+/// A random input of `size` pairs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let from = rng.range(1..=99);
+        format!("{}-{}", from, rng.range(from..=99))
+    };
+    let pairs: Vec<String> = (0..size)
+        .map(|_| format!("{},{}", range(), range()))
+        .collect();
+    pairs.join("\n")
+}
+
+pub struct Day04;
+
+impl crate::Solution for Day04 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,177 @@
+use std::cmp::min;
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 5;
+
+fn stacks(input: &str) -> Vec<Vec<char>> {
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    for line in input.lines().take_while(|line| line.contains('[')) {
+        for (idx, start) in (0..line.len()).step_by(4).enumerate() {
+            if idx >= stacks.len() {
+                stacks.push(Vec::new());
+            }
+
+            let end = min(line.len(), start + 4);
+            if let Some(c) = line[start..end].chars().find(char::is_ascii_alphabetic) {
+                stacks[idx].push(c);
+            }
+        }
+    }
+
+    for stack in &mut stacks {
+        stack.reverse();
+    }
+
+    stacks
+}
+
+struct Move {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+fn moves(input: &str, stacks: usize) -> Result<Vec<Move>, parse::Error> {
+    let p = Parser::new(DAY, input);
+    let stack = |s| match p.num::<usize>(s)? {
+        idx @ 1.. if idx <= stacks => Ok(idx - 1),
+        _ => Err(p.error(s, format!("expected stack between 1 and {}", stacks))),
+    };
+
+    let mut moves = Vec::new();
+    for line in input.lines().skip_while(|line| !line.starts_with("move")) {
+        let (count, rest) = p.split_once(p.strip_prefix(line, "move ")?, " from ")?;
+        let (from, to) = p.split_once(rest, " to ")?;
+        moves.push(Move {
+            count: p.num(count)?,
+            from: stack(from)?,
+            to: stack(to)?,
+        });
+    }
+    Ok(moves)
+}
+
+fn perform(input: &str, mut stacks: Vec<Vec<char>>, is_9001: bool) -> crate::Result<String> {
+    for Move { count, from, to } in moves(input, stacks.len())? {
+        let end = stacks[from]
+            .len()
+            .checked_sub(count)
+            .ok_or_else(|| {
+                crate::Error::solve(DAY, format!("not enough crates on stack {}", from + 1))
+            })?;
+        let mut crates = stacks[from].split_off(end);
+        if !is_9001 {
+            crates.reverse();
+        }
+        stacks[to].extend(crates);
+    }
+
+    stacks
+        .into_iter()
+        .enumerate()
+        .map(|(idx, mut stack)| {
+            stack
+                .pop()
+                .ok_or_else(|| crate::Error::solve(DAY, format!("stack {} is empty", idx + 1)))
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> crate::Result<String> {
+    let stacks = stacks(input);
+    perform(input, stacks, false)
+}
+
+pub fn part2(input: &str) -> crate::Result<String> {
+    let stacks = stacks(input);
+    perform(input, stacks, true)
+}
+
+/// A random input of up to nine stacks and `size` moves. Moves never take the last crate of a
+/// stack, so there are always enough crates and a crate on top of each stack in the end.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut stacks: Vec<Vec<char>> = (0..rng.range(3..=9))
+        .map(|_| {
+            (0..rng.range(1..=8))
+                .map(|_| rng.range(b'A'..=b'Z') as char)
+                .collect()
+        })
+        .collect();
+    stacks[0].push(rng.range(b'A'..=b'Z') as char);
+
+    let mut lines = Vec::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for row in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        lines.push(row.join(" ").trim_end().to_string());
+    }
+    let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect();
+    lines.push(numbers.join(" "));
+    lines.push(String::new());
+
+    for _ in 0..size {
+        let from: Vec<usize> = (0..stacks.len())
+            .filter(|idx| stacks[*idx].len() > 1)
+            .collect();
+        let from = *rng.pick(&from);
+        let to = (from + rng.range(1..=stacks.len() - 1)) % stacks.len();
+        let count = rng.range(1..=stacks[from].len() - 1);
+        let end = stacks[from].len() - count;
+        let crates = stacks[from].split_off(end);
+        stacks[to].extend(crates);
+        lines.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+    }
+    lines.join("\n")
+}
+
+pub struct Day05;
+
+impl crate::Solution for Day05 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        let stacks = stacks(input);
+        crate::solution::parsed(moves(input, stacks.len()).map(|moves| (stacks, moves)))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use crate::random::Rng;
+
+const DAY: u32 = 6;
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    for idx in 0..input.len().saturating_sub(3) {
+        let set: HashSet<char> = input[idx..idx + 4].chars().collect();
+        if set.len() == 4 {
+            return Ok(idx + 4);
+        }
+    }
+    Err(crate::Error::solve(DAY, "no start-of-packet marker found"))
+}
+
+pub fn part2(input: &str) -> crate::Result<usize> {
+    for idx in 0..input.len().saturating_sub(13) {
+        let set: HashSet<char> = input[idx..idx + 14].chars().collect();
+        if set.len() == 14 {
+            return Ok(idx + 14);
+        }
+    }
+    Err(crate::Error::solve(DAY, "no start-of-message marker found"))
+}
+
+/// A random datastream of `size` characters, at least enough for a start-of-message marker.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // few distinct letters make markers rare, so one is put at a random position
+    let mut stream: Vec<char> = (0..size.max(14))
+        .map(|_| rng.range(b'a'..=b'f') as char)
+        .collect();
+    let mut marker: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut marker);
+    let start = rng.range(0..=stream.len() - 14);
+    stream[start..start + 14].copy_from_slice(&marker[..14]);
+    stream.into_iter().collect()
+}
+
+pub struct Day06;
+
+impl crate::Solution for Day06 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 7;
+
+params! {
+    /// The size of the disk.
+    space: i32 = 70000000,
+    /// The free space needed for the update.
+    required: i32 = 30000000,
+}
+
+#[derive(Debug)]
+enum Entry {
+    Dir(Vec<usize>),
+    File(i32),
+}
+
+impl Entry {
+    fn dir(&mut self) -> Option<&mut Vec<usize>> {
+        match self {
+            Entry::Dir(dir) => Some(dir),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Fs<'a> {
+    entries: Vec<Entry>,
+    lookup: HashMap<Vec<&'a str>, usize>,
+}
+
+impl<'a> Fs<'a> {
+    fn add_with<F>(&mut self, path: &Vec<&'a str>, add: F)
+    where
+        F: Fn() -> Entry,
+    {
+        if !self.lookup.contains_key(path) {
+            self.entries.push(add());
+            let child = self.entries.len() - 1;
+            self.lookup.insert(path.clone(), child);
+
+            let parent = self.lookup[&path[..path.len() - 1]];
+            self.entries[parent].dir().unwrap().push(child);
+        }
+    }
+
+    fn from_shell_output(output: &'a str) -> Result<Self, parse::Error> {
+        let p = Parser::new(DAY, output);
+        let mut fs = Fs {
+            entries: vec![Entry::Dir(Vec::new())],
+            lookup: HashMap::new(),
+        };
+        fs.lookup.insert(vec!["/"], 0);
+
+        let mut path = vec!["/"];
+        for line in output.lines() {
+            if let Some(dir) = line.strip_prefix("$ cd ") {
+                match dir {
+                    "/" => _ = path.split_off(1),
+                    ".." if path.len() == 1 => return Err(p.error(dir, "already at '/'")),
+                    ".." => _ = path.pop(),
+                    dir => {
+                        path.push(dir);
+                        fs.add_with(&path, || Entry::Dir(Vec::new()));
+                    }
+                }
+            } else if line != "$ ls" && !line.starts_with("dir ") {
+                // file
+                let (size, name) = p.split_once(line, " ")?;
+                let size = p.num(size)?;
+
+                path.push(name);
+                fs.add_with(&path, || Entry::File(size));
+                path.pop();
+            }
+        }
+
+        Ok(fs)
+    }
+
+    fn p1(&self, node: usize) -> (i32, i32) {
+        match &self.entries[node] {
+            Entry::Dir(entries) => {
+                let (mut size, mut p1) = (0, 0);
+                for child in entries {
+                    let (entry_size, entry_p1) = self.p1(*child);
+                    size += entry_size;
+                    p1 += entry_p1;
+                }
+
+                if size <= 100000 {
+                    p1 += size;
+                }
+
+                (size, p1)
+            }
+            Entry::File(size) => (*size, 0),
+        }
+    }
+}
+
+pub fn part1(input: &str) -> crate::Result<i32> {
+    let fs = Fs::from_shell_output(input)?;
+    let (_, p1) = fs.p1(0);
+    Ok(p1)
+}
+
+pub fn part2(input: &str, params: &Params) -> crate::Result<i32> {
+    let fs = Fs::from_shell_output(input)?;
+    let (used, _) = fs.p1(0);
+    let free = params.space - used;
+    let target = params.required - free;
+
+    let mut best = used;
+    for (idx, node) in fs.entries.iter().enumerate() {
+        if let Entry::Dir(_) = node {
+            let (size, _) = fs.p1(idx);
+            if size >= target && size < best {
+                best = size;
+            }
+        }
+    }
+    Ok(best)
+}
+
+/// A name of a few letters not in `taken`, which it is added to.
+fn name(rng: &mut Rng, taken: &mut HashSet<String>) -> String {
+    loop {
+        let mut name: String = (0..rng.range(1..=8))
+            .map(|_| rng.range(b'a'..=b'z') as char)
+            .collect();
+        if rng.chance(0.3) {
+            let extension = rng.pick(&[".txt", ".dat", ".log", ".lst", ".ext"]);
+            name.push_str(extension);
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+/// The files of a generated directory with their sizes, and its subdirectories by index.
+type Listing = (Vec<(String, i32)>, Vec<(String, usize)>);
+
+/// Appends the commands listing directory `dir` of `dirs` and then its subdirectories to `lines`.
+fn browse(dirs: &[Listing], dir: usize, lines: &mut Vec<String>) {
+    let (files, subdirs) = &dirs[dir];
+    lines.push("$ ls".to_string());
+    for (name, _) in subdirs {
+        lines.push(format!("dir {}", name));
+    }
+    for (name, size) in files {
+        lines.push(format!("{} {}", size, name));
+    }
+    for (name, subdir) in subdirs {
+        lines.push(format!("$ cd {}", name));
+        browse(dirs, *subdir, lines);
+        lines.push("$ cd ..".to_string());
+    }
+}
+
+/// A random terminal output browsing `size` directories, which leave less free space on the disk
+/// than the update requires, but at least half of it.
+pub fn generate(rng: &mut Rng, size: usize, params: &Params) -> String {
+    // the first directory is `/`
+    let mut dirs: Vec<Listing> = vec![Default::default()];
+    let mut names = vec![HashSet::new()];
+    for dir in 1..size.max(1) {
+        let parent = rng.range(0..=dir - 1);
+        let name = name(rng, &mut names[parent]);
+        dirs[parent].1.push((name, dir));
+        dirs.push(Default::default());
+        names.push(HashSet::new());
+    }
+
+    let mut used = 0;
+    for (dir, names) in names.iter_mut().enumerate() {
+        for _ in 0..rng.range(0..=4) {
+            let size = rng.range(1..=300000);
+            dirs[dir].0.push((name(rng, names), size));
+            used += size;
+        }
+    }
+    let target = params.space - rng.range(params.required / 2..=(params.required - 1).max(0));
+    if used < target {
+        let dir = rng.range(0..=dirs.len() - 1);
+        let name = name(rng, &mut names[dir]);
+        dirs[dir].0.push((name, target - used));
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    browse(&dirs, 0, &mut lines);
+    lines.join("\n")
+}
+
+pub struct Day07;
+
+impl crate::Solution for Day07 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(Fs::from_shell_output(input))
+    }
+
+    fn params(
+        &self,
+        overrides: &crate::params::Overrides,
+    ) -> crate::Result<Vec<(&'static str, String)>> {
+        Params::new(overrides).map(|params| params.values())
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(Params::new(params).and_then(|params| part2(input, &params).map(Into::into)))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Params::new(params).map(|params| generate(rng, size, &params)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ex1() {
+        let text = include_str!("../../../examples/2022/day07-1.txt");
+        let example = crate::examples::Example::parse("day07-1.txt", text).unwrap();
+
+        let fs = Fs::from_shell_output(&example.input).unwrap();
+        let path = vec!["/", "a", "e"];
+        let node = fs.lookup[&path];
+        let (size_e, p1_e) = fs.p1(node);
+        assert_eq!(584, size_e);
+        assert_eq!(584, p1_e);
+    }
+}
@@ -0,0 +1,134 @@
+use std::iter;
+
+use crate::{
+    grid::{Grid, Pos},
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 8;
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+const DIRECTIONS: &[Direction] = &[
+    Direction::Right,
+    Direction::Left,
+    Direction::Down,
+    Direction::Up,
+];
+
+/// Positions from `pos` towards `dir`, not including `pos` itself.
+fn line(pos: Pos, dir: Direction) -> impl Iterator<Item = Pos> {
+    let mut needle = pos;
+    iter::from_fn(move || {
+        match dir {
+            Direction::Right => needle.0 += 1,
+            Direction::Left => needle.0 -= 1,
+            Direction::Down => needle.1 += 1,
+            Direction::Up => needle.1 -= 1,
+        }
+        Some(needle)
+    })
+}
+
+fn parse(input: &str) -> Result<Grid<u32>, parse::Error> {
+    Grid::parse(&Parser::new(DAY, input), input, |c| c.to_digit(10))
+}
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    let trees = parse(input)?;
+    let num_visible = trees
+        .iter()
+        .filter(|(pos, height)| {
+            DIRECTIONS.iter().any(|dir| {
+                line(*pos, *dir)
+                    .map_while(|pos| trees.get(pos))
+                    .all(|other| other < height)
+            })
+        })
+        .count();
+    Ok(num_visible)
+}
+
+pub fn part2(input: &str) -> crate::Result<i32> {
+    let trees = parse(input)?;
+    let score = trees
+        .iter()
+        .map(|(pos, height)| {
+            DIRECTIONS
+                .iter()
+                .map(|dir| {
+                    let mut score = 0;
+                    for other in line(pos, *dir).map_while(|pos| trees.get(pos)) {
+                        score += 1;
+                        if other >= height {
+                            break;
+                        }
+                    }
+                    score
+                })
+                .product::<i32>()
+        })
+        .max()
+        .unwrap_or(0);
+    Ok(score)
+}
+
+/// A random map of `size` by `size` trees.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rows: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| rng.range(b'0'..=b'9') as char)
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+pub struct Day08;
+
+impl crate::Solution for Day08 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
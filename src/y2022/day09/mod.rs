@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+use crate::{
+    geom::{Bounds, Dir, Point2},
+    grid::Grid,
+    parse::{self, Parser},
+    random::Rng,
+    visualize::Frames,
+};
+
+const DAY: u32 = 9;
+
+type Point = Point2<i32>;
+
+struct Rope {
+    knots: Vec<Point>,
+    visited: HashSet<Point>,
+}
+
+impl Rope {
+    fn new(len: usize) -> Self {
+        let mut visited = HashSet::new();
+        visited.insert(Point::default());
+
+        Rope {
+            knots: vec![Point::default(); len],
+            visited,
+        }
+    }
+
+    fn follow(&mut self, idx: usize) {
+        let (head, tail) = (self.knots[idx - 1], self.knots[idx]);
+        if head.chebyshev(tail) > 1 {
+            self.knots[idx] += (head - tail).signum();
+        }
+    }
+
+    fn mv(&mut self, dir: Dir, cnt: i32) {
+        for _ in 0..cnt {
+            self.knots[0] += dir.delta();
+
+            for idx in 1..self.knots.len() {
+                self.follow(idx);
+            }
+
+            self.visited.insert(*self.knots.last().unwrap());
+        }
+    }
+
+    /// The rope within `bounds` as drawn in the puzzle: the head `H` and the other knots by
+    /// number, or `T` for the tail of a rope of two knots, over the start `s` and the positions
+    /// the tail visited `#`.
+    fn draw(&self, bounds: Bounds<Point>) -> Grid<char> {
+        let size = bounds.max - bounds.min + Point::new(1, 1);
+        let mut grid = Grid::new(size.x as usize, size.y as usize, '.');
+        let at = |p: Point| (p - bounds.min).into();
+        for p in &self.visited {
+            grid[at(*p)] = '#';
+        }
+        grid[at(Point::default())] = 's';
+        for (idx, knot) in self.knots.iter().enumerate().rev() {
+            grid[at(*knot)] = match idx {
+                0 => 'H',
+                1 if self.knots.len() == 2 => 'T',
+                _ => char::from_digit(idx as u32, 36).unwrap_or('*'),
+            };
+        }
+        grid
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(Dir, i32)>, parse::Error> {
+    let p = Parser::new(DAY, input);
+    input
+        .lines()
+        .map(|line| {
+            let (dir, cnt) = p.split_once(line, " ")?;
+            let dir = match dir {
+                "R" => Dir::Right,
+                "L" => Dir::Left,
+                "D" => Dir::Down,
+                "U" => Dir::Up,
+                _ => return Err(p.error(dir, "expected one of 'R', 'L', 'D' or 'U'")),
+            };
+            Ok((dir, p.num(cnt)?))
+        })
+        .collect()
+}
+
+/// Moves a rope of `len` knots as given by `input`, drawing it after each move, and returns the
+/// number of positions its tail visited.
+fn simulate(input: &str, len: usize, frames: &mut Frames) -> crate::Result<usize> {
+    let moves = parse(input)?;
+
+    // the knots stay within the area the head moves in
+    let mut head = Point::default();
+    let path = moves.iter().map(|(dir, cnt)| {
+        head += dir.delta() * *cnt;
+        head
+    });
+    let bounds = Bounds::from_points(path.chain([Point::default()])).unwrap();
+
+    let mut rope = Rope::new(len);
+    for (dir, cnt) in moves {
+        rope.mv(dir, cnt);
+        frames.draw(|| rope.draw(bounds));
+    }
+    Ok(rope.visited.len())
+}
+
+pub fn part1(input: &str, frames: &mut Frames) -> crate::Result<usize> {
+    simulate(input, 2, frames)
+}
+
+pub fn part2(input: &str, frames: &mut Frames) -> crate::Result<usize> {
+    simulate(input, 10, frames)
+}
+
+/// A random input of `size` motions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let motions: Vec<String> = (0..size)
+        .map(|_| format!("{} {}", rng.pick(&['R', 'L', 'U', 'D']), rng.range(1..=20)))
+        .collect();
+    motions.join("\n")
+}
+
+pub struct Day09;
+
+impl crate::Solution for Day09 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Rope Bridge"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input, &mut Frames::off()).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input, &mut Frames::off()).map(Into::into))
+    }
+
+    fn visualize(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+        part: u32,
+        frames: &mut Frames,
+    ) -> Option<crate::Result<crate::Answer>> {
+        match part {
+            1 => Some(part1(input, frames).map(Into::into)),
+            2 => Some(part2(input, frames).map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
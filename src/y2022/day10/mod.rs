@@ -1,9 +1,13 @@
 use std::{cmp::max, fmt::Display};
 
-#[derive(Debug)]
-enum Error {
-    InvalidInput,
-}
+use crate::{
+    grid::Grid,
+    parse::{self, Parser},
+    random::Rng,
+    visualize::Frames,
+};
+
+const DAY: u32 = 10;
 
 #[derive(Debug, Clone, Copy)]
 enum Insn {
@@ -20,13 +24,14 @@ impl Insn {
     }
 }
 
-fn compile(program: &str) -> crate::Result<Vec<Insn>> {
+fn compile(program: &str) -> Result<Vec<Insn>, parse::Error> {
+    let p = Parser::new(DAY, program);
     let mut compiled = Vec::new();
     for line in program.lines() {
-        compiled.push(match &line[..4] {
-            "addx" => Insn::Addx(line[5..].parse()?),
-            "noop" => Insn::Noop,
-            _ => return Err(crate::Error::boxed(Error::InvalidInput)),
+        compiled.push(match line.split_once(' ') {
+            Some(("addx", n)) => Insn::Addx(p.num(n)?),
+            None if line == "noop" => Insn::Noop,
+            _ => return Err(p.error(line, "expected 'addx <n>' or 'noop'")),
         });
     }
     Ok(compiled)
@@ -96,7 +101,7 @@ struct Crt {
 impl Crt {
     fn new() -> Self {
         Crt {
-            pixels: vec![vec!['#'; 40]; 6],
+            pixels: vec![vec![' '; 40]; 6],
         }
     }
 
@@ -112,6 +117,11 @@ impl Crt {
         self.pixels[row][col] = if col >= from && col <= to { '#' } else { '.' };
         true
     }
+
+    /// The screen with the pixels drawn so far.
+    fn draw(&self) -> Grid<char> {
+        Grid::from_rows(self.pixels.iter().cloned(), ' ')
+    }
 }
 
 impl Display for Crt {
@@ -126,15 +136,88 @@ impl Display for Crt {
     }
 }
 
-pub fn part2(input: &str) -> crate::Result<String> {
+pub fn part2(input: &str, frames: &mut Frames) -> crate::Result<String> {
     let program = compile(input)?;
     let mut cpu = Cpu::new(program);
     let mut crt = Crt::new();
 
     let mut cycle = 1; // "during"
     while crt.tick(cycle, cpu.x) {
+        frames.draw(|| crt.draw());
         cycle += 1;
         cpu.tick(1);
     }
     Ok(format!("{}", crt))
 }
+
+/// A random program of `size` instructions, or more to run for all 240 cycles of the screen,
+/// which keeps the sprite near the screen.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (mut lines, mut cycles, mut x) = (Vec::new(), 0, 1);
+    while lines.len() < size || cycles < 240 {
+        if rng.chance(0.3) {
+            lines.push("noop".to_string());
+            cycles += 1;
+        } else {
+            let n = rng.range((-1 - x).max(-20)..=(40 - x).min(20));
+            lines.push(format!("addx {}", n));
+            cycles += 2;
+            x += n;
+        }
+    }
+    lines.join("\n")
+}
+
+pub struct Day10;
+
+impl crate::Solution for Day10 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Cathode-Ray Tube"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(compile(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input, &mut Frames::off()).map(Into::into))
+    }
+
+    fn visualize(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+        part: u32,
+        frames: &mut Frames,
+    ) -> Option<crate::Result<crate::Answer>> {
+        match part {
+            2 => Some(part2(input, frames).map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
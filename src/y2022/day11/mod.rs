@@ -0,0 +1,265 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 11;
+
+params! {
+    /// The number of rounds in the first part, where worry levels are divided by 3.
+    rounds1: u64 = 20,
+    /// The number of rounds in the second part.
+    rounds2: u64 = 10_000,
+}
+
+#[derive(Debug)]
+enum Op {
+    Old,
+    Num(u64),
+}
+
+#[derive(Debug)]
+struct Monkey {
+    items: VecDeque<u64>,
+    operation: (Op, char, Op),
+    test: (u64, usize, usize),
+    throw_count: u64,
+}
+
+impl Monkey {
+    fn inspect<F>(&mut self, op: F) -> crate::Result<Option<(u64, usize)>>
+    where
+        F: Fn(u64, char, u64) -> crate::Result<u64>,
+    {
+        let worry = {
+            let old = match self.items.pop_front() {
+                Some(old) => old,
+                None => return Ok(None),
+            };
+            let op1 = match self.operation.0 {
+                Op::Old => old,
+                Op::Num(n) => n,
+            };
+            let op2 = match self.operation.2 {
+                Op::Old => old,
+                Op::Num(n) => n,
+            };
+            op(op1, self.operation.1, op2)?
+        };
+
+        let to = if worry % self.test.0 == 0 {
+            self.test.1
+        } else {
+            self.test.2
+        };
+
+        self.throw_count += 1;
+        Ok(Some((worry, to)))
+    }
+}
+
+impl FromStr for Monkey {
+    type Err = parse::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let mut lines = s.lines().skip(1);
+        let mut line = |prefix: &str| {
+            let line = lines
+                .next()
+                .ok_or_else(|| p.error_after(s, format!("expected '{}'", prefix.trim())))?;
+            p.strip_prefix(line, prefix)
+        };
+
+        let items = line("  Starting items: ")?
+            .split(", ")
+            .map(|item| p.num(item))
+            .collect::<Result<_, _>>()?;
+        let operation = {
+            let operand = |token| match token {
+                "old" => Ok(Op::Old),
+                _ => p.num(token).map(Op::Num),
+            };
+            let (op1, rest) = p.split_once(line("  Operation: new = ")?, " ")?;
+            let (op, op2) = p.split_once(rest, " ")?;
+            let op = match op {
+                "*" => '*',
+                "+" => '+',
+                _ => return Err(p.error(op, "expected '*' or '+'")),
+            };
+            (operand(op1)?, op, operand(op2)?)
+        };
+        let test = (
+            p.num(line("  Test: divisible by ")?)?,
+            p.num(line("    If true: throw to monkey ")?)?,
+            p.num(line("    If false: throw to monkey ")?)?,
+        );
+        Ok(Monkey {
+            items,
+            operation,
+            test,
+            throw_count: 0,
+        })
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>, parse::Error> {
+    Parser::new(DAY, input).each(input.split("\n\n"))
+}
+
+fn monkey_business<F>(mut monkeys: Vec<Monkey>, rounds: u64, op: F) -> crate::Result<u64>
+where
+    F: Fn(u64, char, u64) -> crate::Result<u64>,
+{
+    for _ in 0..rounds {
+        for idx in 0..monkeys.len() {
+            while let Some((worry, to)) = monkeys[idx].inspect(&op)? {
+                monkeys
+                    .get_mut(to)
+                    .ok_or_else(|| crate::Error::solve(DAY, format!("no monkey {}", to)))?
+                    .items
+                    .push_back(worry);
+            }
+        }
+    }
+
+    monkeys.sort_by_key(|monkey| monkey.throw_count);
+    let monkey_business = monkeys
+        .into_iter()
+        .rev()
+        .take(2)
+        .map(|monkey| monkey.throw_count)
+        .product();
+    Ok(monkey_business)
+}
+
+pub fn part1(input: &str, params: &Params) -> crate::Result<u64> {
+    let monkeys = parse(input)?;
+
+    monkey_business(monkeys, params.rounds1, |op1, op, op2| match op {
+        '*' => Ok((op1 * op2) / 3),
+        '+' => Ok((op1 + op2) / 3),
+        _ => Err(crate::Error::solve(DAY, format!("unknown operator '{}'", op))),
+    })
+}
+
+pub fn part2(input: &str, params: &Params) -> crate::Result<u64> {
+    let monkeys = parse(input)?;
+
+    let divisor: u64 = monkeys.iter().map(|monkey| monkey.test.0).product();
+    monkey_business(monkeys, params.rounds2, |op1, op, op2| match op {
+        '*' => Ok((op1 * op2) % divisor),
+        '+' => Ok((op1 + op2) % divisor),
+        _ => Err(crate::Error::solve(DAY, format!("unknown operator '{}'", op))),
+    })
+}
+
+/// A random input of between 4 and 8 monkeys holding `size` items between them, at least one
+/// each. Each monkey tests for a different prime, as in the puzzle, and the worry levels of the
+/// first part stay small enough not to overflow.
+pub fn generate(rng: &mut Rng, size: usize, params: &Params) -> String {
+    loop {
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        let count = rng.range(4..=8);
+        let mut items = vec![1; count];
+        for _ in count..size {
+            items[rng.range(0..=count - 1)] += 1;
+        }
+
+        let monkeys: Vec<String> = (0..count)
+            .map(|idx| {
+                let items: Vec<String> = (0..items[idx])
+                    .map(|_| rng.range(50..=99).to_string())
+                    .collect();
+                let operation = match rng.range(0..=4) {
+                    0 => "old * old".to_string(),
+                    1 | 2 => format!("old * {}", rng.range(2..=19)),
+                    _ => format!("old + {}", rng.range(1..=8)),
+                };
+                // two different monkeys other than this one
+                let on_true = (idx + rng.range(1..=count - 1)) % count;
+                let mut on_false = on_true;
+                while on_false == on_true || on_false == idx {
+                    on_false = rng.range(0..=count - 1);
+                }
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}",
+                    idx,
+                    items.join(", "),
+                    operation,
+                    primes[idx],
+                    on_true,
+                    on_false
+                )
+            })
+            .collect();
+        let input = monkeys.join("\n\n");
+
+        let overflow = || crate::Error::solve(DAY, "overflow");
+        let monkeys = parse(&input).expect("generated monkeys");
+        let checked = monkey_business(monkeys, params.rounds1, |op1, op, op2| match op {
+            '*' => op1.checked_mul(op2).map(|n| n / 3).ok_or_else(overflow),
+            _ => op1.checked_add(op2).map(|n| n / 3).ok_or_else(overflow),
+        });
+        if checked.is_ok() {
+            return input;
+        }
+    }
+}
+
+pub struct Day11;
+
+impl crate::Solution for Day11 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Monkey in the Middle"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn params(
+        &self,
+        overrides: &crate::params::Overrides,
+    ) -> crate::Result<Vec<(&'static str, String)>> {
+        Params::new(overrides).map(|params| params.values())
+    }
+
+    fn part1(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> crate::Result<crate::Answer> {
+        part1(input, &Params::new(params)?).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(Params::new(params).and_then(|params| part2(input, &params).map(Into::into)))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Params::new(params).map(|params| generate(rng, size, &params)))
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{
+    geom::Point2,
+    grid::{Grid, Pos},
+    parse::{self, Parser},
+    random::Rng,
+    search,
+};
+
+const DAY: u32 = 12;
+
+const A: i32 = b'a' as i32;
+
+fn no_path() -> crate::Error {
+    crate::Error::solve(DAY, "no path found")
+}
+
+fn parse(input: &str) -> Result<(Grid<i32>, Pos, Pos), parse::Error> {
+    let p = Parser::new(DAY, input);
+    let map = Grid::parse(&p, input, |c| {
+        (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+    })?;
+    let start = map
+        .position(|c| *c == 'S')
+        .ok_or_else(|| p.error_after(input, "no start 'S' found"))?;
+    let goal = map
+        .position(|c| *c == 'E')
+        .ok_or_else(|| p.error_after(input, "no goal 'E' found"))?;
+
+    let map = map.map(|c| match c {
+        'S' => A,
+        'E' => b'z' as i32,
+        c => *c as i32,
+    });
+    Ok((map, start, goal))
+}
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    let (map, start, goal) = parse(input)?;
+    let map = &map;
+    search::a_star(
+        start,
+        |&p| {
+            map.neighbors4(p)
+                .filter(move |np| map[*np] - map[p] <= 1)
+                .map(|np| (np, 1))
+        },
+        |&p| Point2::from(p).manhattan(goal.into()) as usize,
+        |&p| p == goal,
+    )
+    .cost()
+    .ok_or_else(no_path)
+}
+
+pub fn part2(input: &str) -> crate::Result<usize> {
+    let (map, _, goal) = parse(input)?;
+    let map = &map;
+    // walk down from the goal to the closest of the lowest squares
+    search::bfs(
+        goal,
+        |&p| {
+            map.neighbors4(p)
+                .filter(move |np| map[p] - map[*np] <= 1)
+        },
+        |&p| map[p] == A,
+    )
+    .cost()
+    .ok_or_else(no_path)
+}
+
+/// A random heightmap `size` squares wide, at least 26 for a way up to `z`, and a quarter as
+/// high. The map rises from `a` to `z` from left to right, with lower squares scattered in it, so
+/// there is always a way along the top row.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (size.max(26), (size / 4).max(3));
+    let (start, goal) = (rng.range(0..=height - 1), rng.range(0..=height - 1));
+    let rows: Vec<String> = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    let level = (x * 26 / width) as u8;
+                    if x == 0 && y == start {
+                        'S'
+                    } else if x == width - 1 && y == goal {
+                        'E'
+                    } else if y > 0 && x > 0 && x < width - 1 && rng.chance(0.2) {
+                        (b'a' + rng.range(0..=level)) as char
+                    } else {
+                        (b'a' + level) as char
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+pub struct Day12;
+
+impl crate::Solution for Day12 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Hill Climbing Algorithm"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,212 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 13;
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Entry {
+    List(List),
+    Value(Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Value(i32);
+
+impl Value {
+    fn parse(p: &Parser, s: &str, idx: &mut usize) -> Result<Self, parse::Error> {
+        let len = s[*idx..].chars().take_while(char::is_ascii_digit).count();
+        let value = p.num(&s[*idx..*idx + len])?;
+        *idx += len;
+        Ok(Value(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct List(Vec<Entry>);
+
+impl List {
+    fn parse(p: &Parser, s: &str, idx: &mut usize) -> Result<Self, parse::Error> {
+        if !s[*idx..].starts_with('[') {
+            return Err(p.error(&s[*idx..], "expected '['"));
+        }
+        *idx += 1;
+
+        let mut list = Vec::new();
+        loop {
+            match s[*idx..].chars().next() {
+                Some('[') => list.push(Entry::List(List::parse(p, s, idx)?)),
+                Some(c) if c.is_ascii_digit() => list.push(Entry::Value(Value::parse(p, s, idx)?)),
+                Some(']') => {
+                    *idx += 1;
+                    return Ok(List(list));
+                }
+                Some(',') => *idx += 1,
+                _ => return Err(p.error(&s[*idx..], "expected '[', ']', ',' or a number")),
+            }
+        }
+    }
+}
+
+impl FromStr for List {
+    type Err = parse::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let mut idx = 0;
+        let list = List::parse(&p, s, &mut idx)?;
+        if idx < s.len() {
+            return Err(p.error(&s[idx..], "expected end of packet"));
+        }
+        Ok(list)
+    }
+}
+
+impl Ord for List {
+    fn cmp(&self, other: &List) -> Ordering {
+        for entries in self.0.iter().zip(other.0.iter()) {
+            match entries {
+                (Entry::Value(lhs), Entry::Value(rhs)) => {
+                    let cmp = lhs.0.cmp(&rhs.0);
+                    if cmp != Ordering::Equal {
+                        return cmp;
+                    }
+                }
+                (Entry::List(lhs), Entry::List(rhs)) => {
+                    let cmp = lhs.cmp(rhs);
+                    if cmp != Ordering::Equal {
+                        return cmp;
+                    }
+                }
+                (lhs, Entry::List(rhs)) => {
+                    let lhs = List(vec![lhs.clone()]);
+                    let cmp = lhs.cmp(rhs);
+                    if cmp != Ordering::Equal {
+                        return cmp;
+                    }
+                }
+                (Entry::List(lhs), rhs) => {
+                    let rhs = List(vec![rhs.clone()]);
+                    let cmp = lhs.cmp(&rhs);
+                    if cmp != Ordering::Equal {
+                        return cmp;
+                    }
+                }
+            }
+        }
+        self.0.len().cmp(&other.0.len())
+    }
+}
+
+impl PartialOrd for List {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<List>, parse::Error> {
+    let lists = Parser::new(DAY, input).each(input.lines().filter(|l| !l.is_empty()))?;
+    if lists.len() % 2 != 0 {
+        return Err(Parser::new(DAY, input).error_after(input, "expected another packet"));
+    }
+    Ok(lists)
+}
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    let cnt = parse(input)?
+        .chunks(2)
+        .enumerate()
+        .filter_map(|(idx, pair)| {
+            if pair[0] <= pair[1] {
+                Some(1 + idx)
+            } else {
+                None
+            }
+        })
+        .sum();
+    Ok(cnt)
+}
+
+pub fn part2(input: &str) -> crate::Result<usize> {
+    let mut lists = parse(input)?;
+
+    let div1: List = "[[2]]".parse()?;
+    lists.push(div1.clone());
+    let div2: List = "[[6]]".parse()?;
+    lists.push(div2.clone());
+
+    lists.sort();
+    let product = lists
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| *l == &div1 || *l == &div2)
+        .map(|(idx, _)| 1 + idx)
+        .product();
+    Ok(product)
+}
+
+/// A random packet, nested at most `depth` lists deeper.
+fn packet(rng: &mut Rng, depth: u32) -> String {
+    let entries: Vec<String> = (0..rng.range(0..=4))
+        .map(|_| {
+            if depth > 0 && rng.chance(0.3) {
+                packet(rng, depth - 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect();
+    format!("[{}]", entries.join(","))
+}
+
+/// A random input of `size` pairs of packets.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size)
+        .map(|_| format!("{}\n{}", packet(rng, 4), packet(rng, 4)))
+        .collect();
+    pairs.join("\n\n")
+}
+
+pub struct Day13;
+
+impl crate::Solution for Day13 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Distress Signal"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,196 @@
+use std::cmp::{max, min};
+
+use crate::{
+    geom::{Bounds, Point2},
+    grid::{Grid, Pos},
+    parse::{self, Parser},
+    random::Rng,
+    visualize::Frames,
+};
+
+const DAY: u32 = 14;
+
+fn below(p: Pos) -> impl Iterator<Item = Pos> {
+    [(p.0, p.1 + 1), (p.0 - 1, p.1 + 1), (p.0 + 1, p.1 + 1)].into_iter()
+}
+
+/// Parses the cave down to and including the floor, wide enough for sand to pile up to the
+/// source, which is returned along with it.
+fn parse(input: &str) -> Result<(Grid<char>, Pos), parse::Error> {
+    let p = Parser::new(DAY, input);
+    let paths = input
+        .lines()
+        .map(|line| {
+            line.split(" -> ")
+                .map(|point| {
+                    let (x, y) = p.split_once(point, ",")?;
+                    // sand comes in at the top, there is no room above it
+                    Ok(Point2::new(p.num(x)?, p.num::<u16>(y)?.into()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let rocks = Bounds::from_points(paths.iter().flatten().copied())
+        .ok_or_else(|| p.error_after(input, "expected a path of rock"))?;
+    let floor = rocks.max.y + 2;
+    let xmin = min(500 - floor - 1, rocks.min.x);
+    let xmax = max(500 + floor + 1, rocks.max.x);
+
+    let mut map = Grid::new((xmax - xmin + 1) as usize, (floor + 1) as usize, '.');
+    for path in &paths {
+        for points in path.windows(2) {
+            let Bounds { min, max } = Bounds::from_points(points.iter().copied()).unwrap();
+            for x in min.x..=max.x {
+                for y in min.y..=max.y {
+                    map[(x - xmin, y)] = '#';
+                }
+            }
+        }
+    }
+    Ok((map, (500 - xmin, 0)))
+}
+
+/// The cave with the source of the sand drawn as `+` unless it is blocked.
+fn draw(map: &Grid<char>, source: Pos) -> Grid<char> {
+    let mut map = map.clone();
+    if map[source] == '.' {
+        map[source] = '+';
+    }
+    map
+}
+
+pub fn part1(input: &str, frames: &mut Frames) -> crate::Result<usize> {
+    let (mut map, source) = parse(input)?;
+    let ymax = map.height() as i32 - 3;
+    let mut cnt = 0;
+    'outer: loop {
+        let mut p = source;
+        while p.1 <= ymax {
+            if let Some(pp) = below(p).find(|p| map[*p] == '.') {
+                p = pp;
+                continue;
+            }
+
+            map[p] = 'o';
+            cnt += 1;
+            frames.draw(|| draw(&map, source));
+            continue 'outer;
+        }
+
+        return Ok(cnt);
+    }
+}
+
+/// How many units of sand settle between frames of the second part, which takes many more.
+const GRAINS_PER_FRAME: usize = 50;
+
+pub fn part2(input: &str, frames: &mut Frames) -> crate::Result<usize> {
+    let (mut map, source) = parse(input)?;
+    let floor = map.height() as i32 - 1;
+    let mut cnt = 0;
+    loop {
+        if cnt % GRAINS_PER_FRAME == 0 {
+            frames.draw(|| draw(&map, source));
+        }
+
+        let mut p = source;
+        loop {
+            if p.1 == floor - 1 {
+                map[p] = 'o';
+                cnt += 1;
+                break;
+            }
+
+            if let Some(pp) = below(p).find(|p| map[*p] == '.') {
+                p = pp;
+                continue;
+            }
+
+            map[p] = 'o';
+            cnt += 1;
+            if p == source {
+                frames.draw(|| draw(&map, source));
+                return Ok(cnt);
+            }
+            break;
+        }
+    }
+}
+
+/// A random scan of `size` paths of rock below the source of the sand.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let paths: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let mut point = Point2::new(rng.range(490..=510), rng.range(2..=30));
+            let mut points = vec![point];
+            for _ in 0..rng.range(1..=4) {
+                let len = rng.range(1..=6);
+                if rng.chance(0.5) {
+                    point.x += *rng.pick(&[-len, len]);
+                } else {
+                    point.y = (point.y + *rng.pick(&[-len, len])).max(2);
+                }
+                points.push(point);
+            }
+            let points: Vec<String> = points.iter().map(|p| format!("{},{}", p.x, p.y)).collect();
+            points.join(" -> ")
+        })
+        .collect();
+    paths.join("\n")
+}
+
+pub struct Day14;
+
+impl crate::Solution for Day14 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Regolith Reservoir"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input, &mut Frames::off()).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input, &mut Frames::off()).map(Into::into))
+    }
+
+    fn visualize(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+        part: u32,
+        frames: &mut Frames,
+    ) -> Option<crate::Result<crate::Answer>> {
+        match part {
+            1 => Some(part1(input, frames).map(Into::into)),
+            2 => Some(part2(input, frames).map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
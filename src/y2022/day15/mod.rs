@@ -0,0 +1,309 @@
+use std::{
+    cmp::{max, min},
+    str::FromStr,
+};
+
+use crate::{
+    geom::Point2,
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 15;
+
+params! {
+    /// The row to count the positions that cannot contain a beacon in.
+    y: i64 = 2000000,
+    /// The largest coordinate the distress beacon can have.
+    max: i64 = 4000000,
+}
+
+type Point = Point2<i64>;
+
+#[derive(Debug, Clone, Copy)]
+struct Range(i64, i64);
+
+impl Range {
+    fn contains(&self, n: i64) -> bool {
+        n >= self.0 && n <= self.1
+    }
+}
+
+#[derive(Debug)]
+struct Sensor {
+    pos: Point,
+    beacon: Point,
+    range: i64,
+}
+
+impl Sensor {
+    fn xrange(&self, y: i64) -> Option<Range> {
+        let perpendicular_dist = self.pos.manhattan(Point::new(self.pos.x, y));
+        if perpendicular_dist <= self.range {
+            Some(Range(
+                self.pos.x - (self.range - perpendicular_dist),
+                self.pos.x + (self.range - perpendicular_dist),
+            ))
+        } else {
+            None
+        }
+    }
+
+    fn in_range(&self, pos: Point) -> bool {
+        pos.manhattan(self.pos) <= self.range
+    }
+
+    fn candidates(&self) -> impl Iterator<Item = Point> {
+        let xmin = self.pos.x - self.range - 1;
+        let xmax = self.pos.x + self.range + 1;
+        let ymin = self.pos.y - self.range - 1;
+        let ymax = self.pos.y + self.range + 1;
+
+        let xs = (xmin..=xmax).rev().chain(xmin..=xmax);
+        let ys = (ymin..=self.pos.y)
+            .rev()
+            .chain(ymin..=ymax)
+            .chain((self.pos.y..=ymax).rev());
+
+        xs.zip(ys).map(|(x, y)| Point::new(x, y))
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = parse::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        let point = |s| -> Result<Point, parse::Error> {
+            let (x, y) = p.split_once(p.strip_prefix(s, "x=")?, ", y=")?;
+            Ok(Point::new(p.num(x)?, p.num(y)?))
+        };
+
+        let s = p.strip_prefix(s, "Sensor at ")?;
+        let (pos, beacon) = p.split_once(s, ": closest beacon is at ")?;
+        let (pos, beacon) = (point(pos)?, point(beacon)?);
+        let range = pos.manhattan(beacon);
+
+        Ok(Sensor { pos, beacon, range })
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Sensor>, parse::Error> {
+    Parser::new(DAY, input).lines()
+}
+
+pub fn part1(input: &str, params: &Params) -> crate::Result<usize> {
+    let sensors = parse(input)?;
+
+    let y = params.y;
+    let Range(from, to) = sensors
+        .iter()
+        .filter_map(|s| s.xrange(y))
+        .reduce(|r1, r2| Range(min(r1.0, r2.0), max(r1.1, r2.1)))
+        .ok_or_else(|| crate::Error::solve(DAY, format!("no sensor covers row {}", y)))?;
+
+    let cnt = (from..=to)
+        .filter(|x| {
+            sensors
+                .iter()
+                .any(|s| s.in_range(Point::new(*x, y)) && Point::new(*x, y) != s.beacon)
+        })
+        .count();
+
+    Ok(cnt)
+}
+
+pub fn part2(input: &str, params: &Params) -> crate::Result<i64> {
+    let sensors = parse(input)?;
+    let range = Range(0, params.max);
+    for sensor in &sensors {
+        if let Some(p) = sensor.candidates().find(|pos| {
+            range.contains(pos.x)
+                && range.contains(pos.y)
+                && sensors.iter().all(|s| !s.in_range(*pos))
+        }) {
+            return Ok(p.x * 4000000 + p.y);
+        }
+    }
+    Err(crate::Error::solve(DAY, "no position outside of all sensor ranges"))
+}
+
+/// A random report of four sensors around the distress beacon, which leave it as the only
+/// position in the search area outside their ranges, and `size` more sensors that do not reach it.
+pub fn generate(rng: &mut Rng, size: usize, params: &Params) -> String {
+    let max = params.max;
+    // the closer to the middle of the area, the smaller the ranges covering it
+    let middle = max / 4..=max * 3 / 4;
+    let distress = Point::new(rng.range(middle.clone()), rng.range(middle));
+    let j = [distress.x, max - distress.x, distress.y, max - distress.y]
+        .into_iter()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    // each of the four covers a quadrant of the area around the distress beacon but for the
+    // beacon itself, their beacons being on the outer sides of their ranges
+    let mut sensors = Vec::new();
+    for (dx, dy) in [(1, 1), (-1, 1), (-1, -1), (1, -1)] {
+        let pos = distress + Point::new(dx * j, dy * j);
+        let range = 2 * j - 1;
+        let along = rng.range(0..=range);
+        sensors.push((pos, pos + Point::new(dx * along, dy * (range - along))));
+    }
+    let beacons: Vec<Point> = sensors.iter().map(|(_, beacon)| *beacon).collect();
+
+    // the others are placed near a beacon, which must be their only closest one
+    while sensors.len() < size + 4 {
+        let offset = Point::new(rng.range(-max / 4..=max / 4), rng.range(-max / 4..=max / 4));
+        let pos = *rng.pick(&beacons) + offset;
+        let mut dists: Vec<(i64, Point)> =
+            beacons.iter().map(|b| (pos.manhattan(*b), *b)).collect();
+        dists.sort();
+        if dists[0].0 < dists[1].0 && dists[0].0 > 0 && dists[0].0 < pos.manhattan(distress) {
+            sensors.push((pos, dists[0].1));
+        }
+    }
+
+    rng.shuffle(&mut sensors);
+    let lines: Vec<String> = sensors
+        .iter()
+        .map(|(pos, beacon)| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                pos.x, pos.y, beacon.x, beacon.y
+            )
+        })
+        .collect();
+    lines.join("\n")
+}
+
+pub struct Day15;
+
+impl crate::Solution for Day15 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Beacon Exclusion Zone"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn params(
+        &self,
+        overrides: &crate::params::Overrides,
+    ) -> crate::Result<Vec<(&'static str, String)>> {
+        Params::new(overrides).map(|params| params.values())
+    }
+
+    fn part1(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> crate::Result<crate::Answer> {
+        part1(input, &Params::new(params)?).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(Params::new(params).and_then(|params| part2(input, &params).map(Into::into)))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Params::new(params).map(|params| generate(rng, size, &params)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Both parts by checking every position, of the row in the first part and of the search
+    /// area in the second.
+    fn brute_force(input: &str, params: &Params) -> (usize, i64) {
+        let sensors = parse(input).unwrap();
+        let covered = |pos: Point| sensors.iter().any(|s| s.in_range(pos));
+        let from = sensors.iter().map(|s| s.pos.x - s.range).min().unwrap();
+        let to = sensors.iter().map(|s| s.pos.x + s.range).max().unwrap();
+        let row = (from..=to)
+            .map(|x| Point::new(x, params.y))
+            .filter(|pos| covered(*pos) && sensors.iter().all(|s| s.beacon != *pos))
+            .count();
+        let distress = (0..=params.max)
+            .flat_map(|x| (0..=params.max).map(move |y| Point::new(x, y)))
+            .find(|pos| !covered(*pos))
+            .unwrap();
+        (row, distress.x * 4000000 + distress.y)
+    }
+
+    /// A report of sensors anywhere around a search area `max` wide, which cover all of it but a
+    /// random position, unlike the generated ones also at its edges. Each beacon is the only
+    /// closest one to its sensor, as in the puzzle.
+    fn report(rng: &mut Rng, max: i64) -> String {
+        let distress = Point::new(rng.range(0..=max), rng.range(0..=max));
+        let mut sensors: Vec<Sensor> = Vec::new();
+        let area = || (0..=max).flat_map(|x| (0..=max).map(move |y| Point::new(x, y)));
+        while area().any(|pos| pos != distress && sensors.iter().all(|s| !s.in_range(pos))) {
+            let pos = Point::new(rng.range(-max / 2..=max * 3 / 2), rng.range(-max / 2..=max));
+            let reach = pos.manhattan(distress) - 1;
+            if reach < 1 {
+                continue;
+            }
+            let range = rng.range(reach / 2..=reach).max(1);
+            let dx = rng.range(0..=range);
+            let beacon = Point::new(
+                pos.x + dx * rng.pick(&[-1, 1]),
+                pos.y + (range - dx) * rng.pick(&[-1, 1]),
+            );
+            let sensor = Sensor { pos, beacon, range };
+            if sensors.iter().all(|s| {
+                (s.beacon == beacon || !s.in_range(beacon)) && !sensor.in_range(s.beacon)
+            }) {
+                sensors.push(sensor);
+            }
+        }
+
+        let lines: Vec<String> = sensors
+            .iter()
+            .map(|Sensor { pos, beacon, .. }| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    pos.x, pos.y, beacon.x, beacon.y
+                )
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    #[test]
+    fn candidates() {
+        crate::random::assert_agree(
+            2..=24,
+            |rng, size| {
+                let max = size as i64;
+                let params = Params {
+                    y: rng.range(0..=max),
+                    max,
+                };
+                (report(rng, max), params)
+            },
+            |(input, params)| Ok((part1(input, params)?, part2(input, params)?)),
+            |(input, params)| brute_force(input, params),
+        );
+    }
+}
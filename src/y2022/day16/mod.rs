@@ -0,0 +1,361 @@
+use std::{
+    cmp::{max, min},
+    collections::{BTreeSet, HashMap, HashSet, VecDeque},
+};
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+    search,
+};
+
+const DAY: u32 = 16;
+
+params! {
+    /// The minutes until the volcano erupts when opening valves alone.
+    minutes1: i32 = 30,
+    /// The minutes left when opening valves along with an elephant.
+    minutes2: i32 = 26,
+}
+
+/// Cost of moving to a valve there is no way to, too large to ever be worth it.
+const UNREACHABLE: i32 = i32::MAX / 2;
+
+#[derive(Debug, Clone)]
+struct Valve<'a> {
+    pressure: i32,
+    tunnels: HashMap<&'a str, i32>,
+}
+
+#[derive(Debug, Clone)]
+struct Map<'a> {
+    valves: HashMap<&'a str, Valve<'a>>,
+}
+
+impl<'a> Map<'a> {
+    fn parse(s: &'a str) -> Result<Self, parse::Error> {
+        let p = Parser::new(DAY, s);
+        let mut valves = HashMap::new();
+        for line in s.lines() {
+            let (valve, rest) = p.split_once(p.strip_prefix(line, "Valve ")?, " has flow rate=")?;
+            let (pressure, rest) = p.split_once(rest, "; ")?;
+            let pressure = p.num(pressure)?;
+            let tunnels = rest
+                .strip_prefix("tunnels lead to valves ")
+                .or_else(|| rest.strip_prefix("tunnel leads to valve "))
+                .ok_or_else(|| p.error(rest, "expected 'tunnels lead to valves'"))?
+                .split(", ")
+                .map(|valve| (valve, 1))
+                .collect();
+
+            valves.insert(valve, Valve { pressure, tunnels });
+        }
+
+        for valve in valves.values() {
+            if let Some(to) = valve.tunnels.keys().find(|to| !valves.contains_key(*to)) {
+                return Err(p.error(to, format!("no such valve '{}'", to)));
+            }
+        }
+        if !valves.contains_key("AA") {
+            return Err(p.error_after(s, "expected valve 'AA'"));
+        }
+
+        Ok(Map { valves })
+    }
+
+    fn cost(&self, from: &'a str, to: &str) -> Option<i32> {
+        search::dijkstra(
+            from,
+            |at| self.valves[at].tunnels.iter().map(|(to, cost)| (*to, *cost)),
+            |at| *at == to,
+        )
+        .cost()
+    }
+
+    fn fill_costs(&mut self) {
+        let keys: Vec<_> = self.valves.keys().copied().collect();
+        for from in &keys {
+            for to in &keys {
+                if from == to {
+                    continue;
+                }
+
+                if let Some(cost) = self.cost(from, to) {
+                    self.valves.get_mut(from).unwrap().tunnels.insert(to, cost);
+                }
+            }
+        }
+    }
+
+    fn flatten(&mut self) {
+        let keys: Vec<_> = self.valves.keys().copied().filter(|k| *k != "AA").collect();
+        for key in keys {
+            if self.valves[key].pressure > 0 {
+                continue;
+            }
+
+            let valve = self.valves.remove(key).unwrap();
+            for (from_id, from_cost) in &valve.tunnels {
+                for (to_id, to_cost) in &valve.tunnels {
+                    self.valves.get_mut(from_id).unwrap().tunnels.remove(key);
+                    self.valves.get_mut(to_id).unwrap().tunnels.remove(key);
+
+                    if from_id == to_id {
+                        continue;
+                    }
+
+                    self.valves
+                        .get_mut(from_id)
+                        .unwrap()
+                        .tunnels
+                        .entry(to_id)
+                        .and_modify(|cost| *cost = min(*cost, from_cost + to_cost))
+                        .or_insert(from_cost + to_cost);
+
+                    self.valves
+                        .get_mut(to_id)
+                        .unwrap()
+                        .tunnels
+                        .entry(from_id)
+                        .and_modify(|cost| *cost = min(*cost, from_cost + to_cost))
+                        .or_insert(from_cost + to_cost);
+                }
+            }
+        }
+    }
+
+    fn into_vec(self) -> Vec<(i32, Vec<i32>)> {
+        let (mut keys, mut valves): (Vec<&str>, Vec<Valve>) = self.valves.into_iter().unzip();
+
+        // put "AA" in front
+        let idx = keys
+            .iter()
+            .enumerate()
+            .find(|(_, k)| *k == &"AA")
+            .unwrap()
+            .0;
+        keys.swap(0, idx);
+        valves.swap(0, idx);
+
+        let valves: Vec<(i32, Vec<i32>)> = valves
+            .into_iter()
+            .map(|valve| {
+                let tunnels = keys
+                    .iter()
+                    .map(|key| *valve.tunnels.get(key).unwrap_or(&UNREACHABLE))
+                    .collect::<Vec<_>>();
+                (valve.pressure, tunnels)
+            })
+            .collect();
+
+        valves
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    time: i32,
+    released: i32,
+    flow: i32,
+}
+
+impl State {
+    fn new() -> Self {
+        State {
+            time: 0,
+            released: 0,
+            flow: 0,
+        }
+    }
+}
+
+fn search(
+    valves: &[(i32, Vec<i32>)],
+    from: usize,
+    rest: &mut VecDeque<usize>,
+    state: State,
+    limit: i32,
+) -> i32 {
+    if rest.is_empty() {
+        return state.released + state.flow * (limit - state.time);
+    }
+
+    let mut best = 0;
+    for _ in 0..rest.len() {
+        let to = rest.pop_front().unwrap();
+
+        let cost = valves[from].1[to] + 1;
+        if state.time + cost >= limit {
+            best = max(best, state.released + state.flow * (limit - state.time));
+        } else {
+            let mut state = state.clone();
+            state.time += cost;
+            state.released += state.flow * cost;
+            state.flow += valves[to].0;
+            best = max(best, search(valves, to, rest, state, limit));
+        }
+
+        rest.push_back(to);
+    }
+    best
+}
+
+pub fn part1(input: &str, params: &Params) -> crate::Result<i32> {
+    let mut map = Map::parse(input)?;
+    map.flatten();
+    map.fill_costs();
+    let valves = map.into_vec();
+
+    Ok(search(
+        &valves,
+        0,
+        &mut (1..valves.len()).collect(),
+        State::new(),
+        params.minutes1,
+    ))
+}
+
+fn search_ele(
+    valves: &[(i32, Vec<i32>)],
+    me: &mut VecDeque<usize>,
+    ele: &mut VecDeque<usize>,
+    visited: &mut HashSet<Vec<usize>>,
+    limit: i32,
+) -> i32 {
+    let mut key: Vec<usize> = me.iter().copied().collect();
+    key.sort();
+    if !visited.insert(key) {
+        return 0;
+    }
+
+    let mut best =
+        search(valves, 0, me, State::new(), limit) + search(valves, 0, ele, State::new(), limit);
+
+    for _ in 0..me.len() {
+        ele.push_back(me.pop_front().unwrap());
+        best = max(best, search_ele(valves, me, ele, visited, limit));
+        me.push_back(ele.pop_back().unwrap());
+    }
+    best
+}
+
+pub fn part2(input: &str, params: &Params) -> crate::Result<i32> {
+    let mut map = Map::parse(input)?;
+    map.flatten();
+    map.fill_costs();
+    let valves = map.into_vec();
+
+    let mut me = (1..valves.len()).collect();
+    let mut ele = VecDeque::new();
+    Ok(search_ele(
+        &valves,
+        &mut me,
+        &mut ele,
+        &mut HashSet::new(),
+        params.minutes2,
+    ))
+}
+
+/// A random scan of `size` valves, at least two, connected by tunnels. About a third of them have
+/// a flow rate, but no more than 15, as opening more takes long to plan for.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names: Vec<String> = (b'A'..=b'Z')
+        .flat_map(|a| (b'A'..=b'Z').map(move |b| format!("{}{}", a as char, b as char)))
+        .filter(|name| name != "AA")
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(2, 26 * 26) - 1);
+    names.insert(0, "AA".to_string());
+
+    // a tree of tunnels reaching all valves, and a few more
+    let mut tunnels = vec![BTreeSet::new(); names.len()];
+    for to in 1..names.len() {
+        let from = rng.range(0..=to - 1);
+        tunnels[from].insert(to);
+        tunnels[to].insert(from);
+    }
+    let last = names.len() - 1;
+    for _ in 0..names.len() / 2 {
+        let (from, to) = (rng.range(0..=last), rng.range(0..=last));
+        if from != to {
+            tunnels[from].insert(to);
+            tunnels[to].insert(from);
+        }
+    }
+
+    let mut working: Vec<usize> = (1..=last).collect();
+    rng.shuffle(&mut working);
+    working.truncate((names.len() / 3).clamp(1, 15));
+
+    let lines: Vec<String> = names
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            let rate = if working.contains(&idx) {
+                rng.range(1..=25)
+            } else {
+                0
+            };
+            let mut to: Vec<&str> = tunnels[idx].iter().map(|to| names[*to].as_str()).collect();
+            rng.shuffle(&mut to);
+            let tunnels = match to.as_slice() {
+                [to] => format!("tunnel leads to valve {}", to),
+                _ => format!("tunnels lead to valves {}", to.join(", ")),
+            };
+            format!("Valve {} has flow rate={}; {}", name, rate, tunnels)
+        })
+        .collect();
+    lines.join("\n")
+}
+
+pub struct Day16;
+
+impl crate::Solution for Day16 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Proboscidea Volcanium"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(Map::parse(input))
+    }
+
+    fn params(
+        &self,
+        overrides: &crate::params::Overrides,
+    ) -> crate::Result<Vec<(&'static str, String)>> {
+        Params::new(overrides).map(|params| params.values())
+    }
+
+    fn part1(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> crate::Result<crate::Answer> {
+        part1(input, &Params::new(params)?).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(Params::new(params).and_then(|params| part2(input, &params).map(Into::into)))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,277 @@
+use std::iter;
+
+use crate::{
+    cycle::Cycle,
+    grid::{Grid, Pos},
+    parse::{self, Parser},
+    random::Rng,
+    visualize::Frames,
+};
+
+const DAY: u32 = 17;
+
+params! {
+    /// The number of rocks to simulate in the first part.
+    rocks1: usize = 2022,
+    /// The number of rocks to extrapolate the height to in the second part.
+    rocks2: usize = 1000000000000,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Jet {
+    Left,
+    Right,
+}
+
+fn parse(input: &str) -> Result<Vec<Jet>, parse::Error> {
+    let p = Parser::new(DAY, input);
+    if input.is_empty() {
+        return Err(p.error(input, "expected '<' or '>'"));
+    }
+    input
+        .char_indices()
+        .map(|(idx, c)| match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(p.error(&input[idx..], "expected '<' or '>'")),
+        })
+        .collect()
+}
+
+/// The rocks, bottom row first.
+fn shapes() -> Vec<Grid<char>> {
+    "####
+
+.#.
+###
+.#.
+
+..#
+..#
+###
+
+#
+#
+#
+#
+
+##
+##"
+    .split("\n\n")
+    .map(|shape| Grid::from_rows(shape.lines().rev().map(|line| line.chars().collect()), '.'))
+    .collect()
+}
+
+/// The settled rocks, bottom row first.
+struct Chamber {
+    map: Grid<char>,
+}
+
+impl Chamber {
+    fn new() -> Self {
+        Self {
+            map: Grid::new(7, 0, '.'),
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    fn fits(&self, shape: &Grid<char>, (x, y): Pos) -> bool {
+        if x < 0 || x as usize + shape.width() > self.map.width() {
+            return false;
+        }
+
+        !shape
+            .iter()
+            .any(|((dx, dy), c)| *c == '#' && self.map.get((x + dx, y + dy)) == Some(&'#'))
+    }
+
+    fn add(&mut self, shape: &Grid<char>, (x, y): Pos) {
+        let top = y as usize + shape.height();
+        if top > self.map.height() {
+            self.map.resize(top, '.');
+        }
+
+        for ((dx, dy), c) in shape.iter().filter(|(_, c)| **c == '#') {
+            self.map[(x + dx, y + dy)] = *c;
+        }
+    }
+
+    /// Drops `shape` into the chamber, pushed around by the jets from `jets[*jet]` on.
+    fn fall(&mut self, shape: &Grid<char>, jets: &[Jet], jet: &mut usize) {
+        let mut pos = (2, self.height() as i32 + 3);
+        loop {
+            let dx = match jets[*jet] {
+                Jet::Left => -1,
+                Jet::Right => 1,
+            };
+            *jet = (*jet + 1) % jets.len();
+            if self.fits(shape, (pos.0 + dx, pos.1)) {
+                pos.0 += dx;
+            }
+
+            if pos.1 == 0 || !self.fits(shape, (pos.0, pos.1 - 1)) {
+                self.add(shape, pos);
+                return;
+            }
+            pos.1 -= 1;
+        }
+    }
+
+    fn depth(&self, x: usize) -> usize {
+        self.map.column(x).rev().take_while(|c| **c == '.').count()
+    }
+
+    /// The top `rows` rows of the chamber between its walls, along with the floor once it is in
+    /// view, padded with empty rows while the chamber is lower.
+    fn draw(&self, rows: usize) -> Grid<char> {
+        let wall = |row: &[char]| {
+            iter::once('|')
+                .chain(row.iter().copied())
+                .chain(['|'])
+                .collect()
+        };
+        let mut lines: Vec<Vec<char>> = self.map.rows().rev().take(rows).map(wall).collect();
+        lines.resize(rows, wall(&['.'; 7]));
+        if self.height() < rows {
+            lines.push("+-------+".chars().collect());
+        }
+        Grid::from_rows(lines, ' ')
+    }
+}
+
+/// The number of rows at the top of the chamber in frames.
+const ROWS: usize = 40;
+
+pub fn part1(input: &str, params: &Params, frames: &mut Frames) -> crate::Result<usize> {
+    let shapes = shapes();
+    let jets = parse(input)?;
+    let mut jet = 0;
+    let mut chamber = Chamber::new();
+    for shape in shapes.iter().cycle().take(params.rocks1) {
+        chamber.fall(shape, &jets, &mut jet);
+        frames.draw(|| chamber.draw(ROWS));
+    }
+    Ok(chamber.height())
+}
+
+pub fn part2(input: &str, params: &Params, frames: &mut Frames) -> crate::Result<usize> {
+    let shapes = shapes();
+    let jets = parse(input)?;
+
+    // the chamber along with the next shape and jet, which repeat once the top of the chamber
+    // looks the same for the same shape and jet
+    let cycle = Cycle::find(
+        (Chamber::new(), 0, 0),
+        |(chamber, shape, jet)| {
+            chamber.fall(&shapes[*shape], &jets, jet);
+            *shape = (*shape + 1) % shapes.len();
+            frames.draw(|| chamber.draw(ROWS));
+        },
+        |(chamber, shape, jet)| {
+            let depths: Vec<_> = (0..7).map(|x| chamber.depth(x)).collect();
+            (depths, *shape, *jet)
+        },
+        |(chamber, _, _)| chamber.height(),
+    );
+    Ok(cycle.fast_forward(params.rocks2, |height| *height as i64) as usize)
+}
+
+/// A random jet pattern of `size` jets, at least 10, pushing left as often as right. The pattern
+/// is one that keeps all columns of the chamber filling up, as the second part relies on the top
+/// of the chamber to repeat.
+pub fn generate(rng: &mut Rng, size: usize, params: &Params) -> String {
+    let shapes = shapes();
+    loop {
+        let mut jets: Vec<char> = (0..size.max(10))
+            .map(|idx| if idx % 2 == 0 { '<' } else { '>' })
+            .collect();
+        rng.shuffle(&mut jets);
+
+        let pattern: Vec<Jet> = jets
+            .iter()
+            .map(|c| if *c == '<' { Jet::Left } else { Jet::Right })
+            .collect();
+        let (mut chamber, mut jet) = (Chamber::new(), 0);
+        let filling = shapes.iter().cycle().take(params.rocks1).all(|shape| {
+            chamber.fall(shape, &pattern, &mut jet);
+            (0..7).all(|x| chamber.depth(x) < ROWS)
+        });
+        if filling {
+            return jets.into_iter().collect();
+        }
+    }
+}
+
+pub struct Day17;
+
+impl crate::Solution for Day17 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Pyroclastic Flow"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn params(
+        &self,
+        overrides: &crate::params::Overrides,
+    ) -> crate::Result<Vec<(&'static str, String)>> {
+        Params::new(overrides).map(|params| params.values())
+    }
+
+    fn part1(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> crate::Result<crate::Answer> {
+        part1(input, &Params::new(params)?, &mut Frames::off()).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        let params = Params::new(params);
+        Some(params.and_then(|params| part2(input, &params, &mut Frames::off()).map(Into::into)))
+    }
+
+    fn visualize(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+        part: u32,
+        frames: &mut Frames,
+    ) -> Option<crate::Result<crate::Answer>> {
+        let params = match Params::new(params) {
+            Ok(params) => params,
+            Err(err) => return Some(Err(err)),
+        };
+        match part {
+            1 => Some(part1(input, &params, frames).map(Into::into)),
+            2 => Some(part2(input, &params, frames).map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Params::new(params).map(|params| generate(rng, size, &params)))
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use crate::{
+    geom::{Bounds, Point3},
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 18;
+
+type Point = Point3<i32>;
+
+type Points = HashSet<Point>;
+
+fn parse(s: &str) -> Result<Points, parse::Error> {
+    let p = Parser::new(DAY, s);
+    let points: Points = s
+        .lines()
+        .map(|line| {
+            let (x, rest) = p.split_once(line, ",")?;
+            let (y, z) = p.split_once(rest, ",")?;
+            Ok(Point::new(p.num(x)?, p.num(y)?, p.num(z)?))
+        })
+        .collect::<Result<_, _>>()?;
+    if points.is_empty() {
+        return Err(p.error(s, "expected a cube"));
+    }
+    Ok(points)
+}
+
+pub fn part1(input: &str) -> crate::Result<usize> {
+    let boulder: Points = parse(input)?;
+    let surface = boulder
+        .iter()
+        .flat_map(|point| point.neighbors6())
+        .filter(|neighbor| !boulder.contains(neighbor))
+        .count();
+    Ok(surface)
+}
+
+pub fn part2(input: &str) -> crate::Result<usize> {
+    let boulder: Points = parse(input)?;
+    // leave room for the water to flow around the boulder
+    let bb = Bounds::from_points(boulder.iter().copied())
+        .expect("parsing rejects empty input")
+        .grow(1);
+
+    let mut outer = Points::new();
+    outer.insert(bb.min);
+    let mut frontier = vec![bb.min];
+    while let Some(p) = frontier.pop() {
+        for neighbor in p.neighbors6() {
+            if bb.contains(neighbor) && !outer.contains(&neighbor) && !boulder.contains(&neighbor)
+            {
+                outer.insert(neighbor);
+                frontier.push(neighbor);
+            }
+        }
+    }
+
+    let surface = boulder
+        .iter()
+        .flat_map(|point| point.neighbors6())
+        .filter(|neighbor| outer.contains(neighbor))
+        .count();
+    Ok(surface)
+}
+
+/// A random scan of `size` cubes, at least one, packed closely enough to form pockets of air.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = ((size as f64).cbrt() * 1.5) as i32 + 1;
+    let (mut seen, mut cubes) = (HashSet::new(), Vec::new());
+    while cubes.len() < size.max(1) {
+        let cube = Point::new(
+            rng.range(0..=side),
+            rng.range(0..=side),
+            rng.range(0..=side),
+        );
+        if seen.insert(cube) {
+            cubes.push(format!("{},{},{}", cube.x, cube.y, cube.z));
+        }
+    }
+    cubes.join("\n")
+}
+
+pub struct Day18;
+
+impl crate::Solution for Day18 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Boiling Boulders"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
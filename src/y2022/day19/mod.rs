@@ -0,0 +1,229 @@
+use std::{cmp::max, collections::HashMap};
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 19;
+
+params! {
+    /// The minutes to open geodes for in the first part.
+    minutes1: u32 = 24,
+    /// The minutes to open geodes for in the second part.
+    minutes2: u32 = 32,
+    /// The number of blueprints left in the second part.
+    blueprints2: usize = 3,
+}
+
+type Cost = [i32; 3];
+type Blueprint = [Cost; 4];
+
+fn parse(s: &str) -> Result<Vec<Blueprint>, parse::Error> {
+    let p = Parser::new(DAY, s);
+    s.lines()
+        .map(|line| {
+            let (_, rest) = p.split_once(p.strip_prefix(line, "Blueprint ")?, ": ")?;
+            let rest = p.strip_prefix(rest, "Each ore robot costs ")?;
+            let (ore, rest) = p.split_once(rest, " ore. ")?;
+            let rest = p.strip_prefix(rest, "Each clay robot costs ")?;
+            let (clay, rest) = p.split_once(rest, " ore. ")?;
+            let rest = p.strip_prefix(rest, "Each obsidian robot costs ")?;
+            let (obsidian_ore, rest) = p.split_once(rest, " ore and ")?;
+            let (obsidian_clay, rest) = p.split_once(rest, " clay. ")?;
+            let rest = p.strip_prefix(rest, "Each geode robot costs ")?;
+            let (geode_ore, rest) = p.split_once(rest, " ore and ")?;
+            let geode_obsidian = p.strip_suffix(rest, " obsidian.")?;
+
+            Ok([
+                [p.num(ore)?, 0, 0],
+                [p.num(clay)?, 0, 0],
+                [p.num(obsidian_ore)?, p.num(obsidian_clay)?, 0],
+                [p.num(geode_ore)?, 0, p.num(geode_obsidian)?],
+            ])
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Factory {
+    robots: [i32; 4],
+    materials: [i32; 4],
+}
+
+impl Factory {
+    fn new() -> Self {
+        Self {
+            robots: [1, 0, 0, 0],
+            materials: [0, 0, 0, 0],
+        }
+    }
+
+    fn has(&self, cost: &Cost) -> bool {
+        (0..3).all(|i| self.materials[i] >= cost[i])
+    }
+
+    fn take(&mut self, cost: &Cost) {
+        (0..3).for_each(|i| self.materials[i] -= cost[i])
+    }
+
+    fn collect(&mut self) {
+        (0..4).for_each(|i| self.materials[i] += self.robots[i])
+    }
+
+    fn same_or_better(&self, other: &Factory) -> bool {
+        self.materials[0] >= other.materials[0]
+            && self.materials[1] >= other.materials[1]
+            && self.materials[2] >= other.materials[2]
+            && self.materials[3] >= other.materials[3]
+    }
+}
+
+fn search(blueprint: &Blueprint, limit: u32) -> i32 {
+    let robot_limits = blueprint.iter().fold([0, 0, 0], |mut acc, cost| {
+        (0..3).for_each(|i| acc[i] = max(acc[i], cost[i]));
+        acc
+    });
+
+    let mut res = 0;
+    let mut frontier: Vec<Factory> = vec![Factory::new()];
+    for _ in 0..limit {
+        // frontier for next iteration, bucketized by robot count for faster insertion
+        let mut best: HashMap<[i32; 4], Vec<Factory>> = HashMap::new();
+        // filters out strictly worse options
+        let mut insert = |factory: Factory| {
+            let candiates = best.entry(factory.robots).or_default();
+
+            let mut i = 0;
+            while i < candiates.len() {
+                if candiates[i].same_or_better(&factory) {
+                    return;
+                }
+
+                if factory.same_or_better(&candiates[i]) {
+                    candiates.remove(i);
+                    continue;
+                }
+
+                i += 1;
+            }
+
+            candiates.push(factory);
+        };
+
+        for factory in &frontier {
+            for (i, costs) in blueprint.iter().enumerate() {
+                // if already producing more of this per minute than any robot costs, stop building
+                // these robots (except geode robots)
+                if i != 3 && factory.robots[i] >= robot_limits[i] {
+                    continue;
+                }
+
+                if factory.has(costs) {
+                    let mut f = factory.clone();
+                    f.take(costs);
+                    f.collect();
+                    f.robots[i] += 1;
+                    insert(f);
+                }
+            }
+
+            let mut f = factory.clone();
+            f.collect();
+            res = max(res, f.materials[3]);
+            insert(f);
+        }
+        frontier = best.into_values().flatten().collect();
+    }
+
+    res
+}
+
+pub fn part1(input: &str, params: &Params) -> crate::Result<i32> {
+    let mut sum = 0;
+    for (i, blueprint) in parse(input)?.iter().enumerate() {
+        sum += (1 + i as i32) * search(blueprint, params.minutes1);
+    }
+    Ok(sum)
+}
+
+pub fn part2(input: &str, params: &Params) -> crate::Result<i32> {
+    let mut product = 1;
+    for blueprint in parse(input)?.iter().take(params.blueprints2) {
+        product *= search(blueprint, params.minutes2);
+    }
+    Ok(product)
+}
+
+/// A random list of `size` blueprints, at least one, with costs like those of the puzzle.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let blueprints: Vec<String> = (1..=size.max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                 Each obsidian robot costs {} ore and {} clay. \
+                 Each geode robot costs {} ore and {} obsidian.",
+                id,
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(5..=20),
+                rng.range(2..=4),
+                rng.range(7..=20)
+            )
+        })
+        .collect();
+    blueprints.join("\n")
+}
+
+pub struct Day19;
+
+impl crate::Solution for Day19 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Not Enough Minerals"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn params(
+        &self,
+        overrides: &crate::params::Overrides,
+    ) -> crate::Result<Vec<(&'static str, String)>> {
+        Params::new(overrides).map(|params| params.values())
+    }
+
+    fn part1(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> crate::Result<crate::Answer> {
+        part1(input, &Params::new(params)?).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        params: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(Params::new(params).and_then(|params| part2(input, &params).map(Into::into)))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 20;
+
+fn find<T: Copy + Eq>(slice: &[T], value: T) -> (usize, T) {
+    slice
+        .iter()
+        .copied()
+        .enumerate()
+        .find(|(_, val)| *val == value)
+        .unwrap()
+}
+
+fn mix(nums: &[i64], moved: &mut Vec<usize>) {
+    for (id, val) in nums.iter().enumerate() {
+        let old_idx = find(moved, id).0;
+        assert_eq!(moved.remove(old_idx), id);
+
+        let new_idx =
+            (old_idx as i64 + *val).rem_euclid(moved.len() as i64) as usize;
+
+        moved.insert(new_idx, id);
+    }
+}
+
+fn coords(nums: &[i64], moved: &[usize]) -> i64 {
+    let id0 = find(nums, 0).0;
+    let mut idx = find(moved, id0).0;
+    let mut sum = 0;
+    for _ in 0..3 {
+        idx = (idx + 1000) % nums.len();
+        let id = moved[idx];
+        sum += nums[id];
+    }
+    sum
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, parse::Error> {
+    let p = Parser::new(DAY, input);
+    let nums = input.lines().map(|l| p.num(l)).collect::<Result<Vec<_>, _>>()?;
+    if !nums.contains(&0) {
+        return Err(p.error_after(input, "expected a 0"));
+    }
+    Ok(nums)
+}
+
+pub fn part1(input: &str) -> crate::Result<i64> {
+    let nums = parse(input)?;
+    let mut moved: Vec<usize> = (0..nums.len()).collect();
+    mix(&nums, &mut moved);
+    Ok(coords(&nums, &moved))
+}
+
+pub fn part2(input: &str) -> crate::Result<i64> {
+    const KEY: i64 = 811589153;
+    let nums: Vec<i64> = parse(input)?.into_iter().map(|n| n * KEY).collect();
+    let mut moved: Vec<usize> = (0..nums.len()).collect();
+    for _ in 0..10 {
+        mix(&nums, &mut moved);
+    }
+    Ok(coords(&nums, &moved))
+}
+
+/// A random encrypted file of `size` numbers, at least two, one of which is 0.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut nums: Vec<i64> = (1..size.max(2))
+        .map(|_| {
+            let n = rng.range(1..=10000);
+            if rng.chance(0.5) {
+                -n
+            } else {
+                n
+            }
+        })
+        .collect();
+    nums.push(0);
+    rng.shuffle(&mut nums);
+    let nums: Vec<String> = nums.iter().map(i64::to_string).collect();
+    nums.join("\n")
+}
+
+pub struct Day20;
+
+impl crate::Solution for Day20 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Grove Positioning System"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
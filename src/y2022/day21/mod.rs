@@ -0,0 +1,360 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::{Add, Div, Index, IndexMut, Mul, Sub},
+};
+
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 21;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug)]
+enum Action<'a> {
+    Num(i64),
+    Op(&'a str, Op, &'a str),
+}
+
+fn parse(s: &str) -> Result<HashMap<&str, Action<'_>>, parse::Error> {
+    let p = Parser::new(DAY, s);
+    let monkeys: HashMap<_, _> = s
+        .lines()
+        .map(|line| {
+            let (name, action) = p.split_once(line, ": ")?;
+            let action = match action.split(' ').collect::<Vec<_>>()[..] {
+                [name1, op, name2] => {
+                    let op = match op {
+                        "+" => Op::Add,
+                        "-" => Op::Sub,
+                        "*" => Op::Mul,
+                        "/" => Op::Div,
+                        _ => return Err(p.error(op, "expected one of '+', '-', '*' or '/'")),
+                    };
+                    Action::Op(name1, op, name2)
+                }
+                _ => Action::Num(p.num(action)?),
+            };
+            Ok((name, action))
+        })
+        .collect::<Result<_, _>>()?;
+
+    for action in monkeys.values() {
+        if let Action::Op(name1, _, name2) = action {
+            if let Some(name) = [name1, name2].into_iter().find(|n| !monkeys.contains_key(*n)) {
+                return Err(p.error(name, format!("no such monkey '{}'", name)));
+            }
+        }
+    }
+    if !monkeys.contains_key("root") {
+        return Err(p.error_after(s, "expected monkey 'root'"));
+    }
+    Ok(monkeys)
+}
+
+#[derive(Debug, Clone)]
+struct Polynomial(Vec<f64>);
+
+impl Add for Polynomial {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        for (idx, value) in rhs.0.iter().enumerate() {
+            self[idx] += value;
+        }
+        self
+    }
+}
+
+impl Sub for Polynomial {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        for (idx, value) in rhs.0.iter().enumerate() {
+            self[idx] -= value;
+        }
+        self
+    }
+}
+
+impl Mul for Polynomial {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut poly = Polynomial(Vec::new());
+        for (idx1, coeff1) in self.0.iter().enumerate() {
+            for (idx2, coeff2) in rhs.0.iter().enumerate() {
+                poly[idx1 + idx2] += coeff1 * coeff2;
+            }
+        }
+        poly
+    }
+}
+
+impl Div for Polynomial {
+    type Output = Self;
+
+    fn div(mut self, rhs: Self) -> Self::Output {
+        if rhs.0.len() > 2 && (rhs[0] == 0.0 || rhs[1] == 0.0) {
+            panic!("not going to do this");
+        }
+
+        let idx = if rhs[0] != 0.0 { 0 } else { 1 };
+        self.0.iter_mut().for_each(|v| *v /= rhs[idx]);
+        if idx == 1 {
+            self.0.remove(0);
+        }
+        self
+    }
+}
+
+impl Index<usize> for Polynomial {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= self.0.len() {
+            &0.0
+        } else {
+            &self.0[index]
+        }
+    }
+}
+
+impl IndexMut<usize> for Polynomial {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= self.0.len() {
+            self.0.resize(index + 1, 0.0);
+        }
+        &mut self.0[index]
+    }
+}
+
+fn eval(monkeys: &HashMap<&str, Action>, name: &str) -> i64 {
+    match monkeys[name] {
+        Action::Num(num) => num,
+        Action::Op(name1, op, name2) => {
+            let val1 = eval(monkeys, name1);
+            let val2 = eval(monkeys, name2);
+            match op {
+                Op::Add => val1 + val2,
+                Op::Sub => val1 - val2,
+                Op::Mul => val1 * val2,
+                Op::Div => val1 / val2,
+            }
+        }
+    }
+}
+
+pub fn part1(input: &str) -> crate::Result<i64> {
+    let monkeys = parse(input)?;
+    Ok(eval(&monkeys, "root"))
+}
+
+fn eval2(monkeys: &HashMap<&str, Action>, name: &str) -> Polynomial {
+    if name == "humn" {
+        return Polynomial(vec![0.0, 1.0]);
+    }
+
+    match monkeys[name] {
+        Action::Num(num) => Polynomial(vec![num as f64]),
+        Action::Op(name1, op, name2) => {
+            let poly1 = eval2(monkeys, name1);
+            let poly2 = eval2(monkeys, name2);
+            match op {
+                Op::Add => poly1 + poly2,
+                Op::Sub => poly1 - poly2,
+                Op::Mul => poly1 * poly2,
+                Op::Div => poly1 / poly2,
+            }
+        }
+    }
+}
+
+pub fn part2(input: &str) -> crate::Result<i64> {
+    let monkeys = parse(input)?;
+    if let Action::Op(name1, _, name2) = monkeys["root"] {
+        let (poly1, poly2) = (eval2(&monkeys, name1), eval2(&monkeys, name2));
+        let humn = (poly2[0] - poly1[0]) / (poly1[1] - poly2[1]);
+        Ok(humn.round() as i64)
+    } else {
+        let root = input.lines().find(|l| l.starts_with("root: ")).unwrap_or(input);
+        Err(Parser::new(DAY, input)
+            .error(root, "expected 'root' to be an operation")
+            .into())
+    }
+}
+
+/// The largest value a generated monkey yells, so that the sums and products of the first part
+/// do not overflow.
+const LARGEST: i64 = 1_000_000_000_000;
+
+/// Builds the jobs of a troop of generated monkeys.
+struct Troop<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Troop<'_> {
+    /// A new name of four letters.
+    fn name(&mut self) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| self.rng.range(b'a'..=b'z') as char)
+                .collect();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// Adds a monkey yelling `n`, positive, along with up to `size - 1` monkeys it depends on, and
+    /// returns its name. Divisions are exact, as they are in the puzzle.
+    fn number(&mut self, n: i64, size: usize) -> String {
+        let name = self.name();
+        if size < 3 || n > LARGEST / 10 {
+            self.jobs.push(format!("{}: {}", name, n));
+            return name;
+        }
+
+        let divisors: Vec<i64> = (2..=9).filter(|d| n % d == 0).collect();
+        let mut ops = vec!['-', '/'];
+        if n > 1 {
+            ops.push('+');
+        }
+        if !divisors.is_empty() {
+            ops.push('*');
+        }
+        let (a, op, b) = match *self.rng.pick(&ops) {
+            '+' => {
+                let a = self.rng.range(1..=n - 1);
+                (a, '+', n - a)
+            }
+            '-' => {
+                let b = self.rng.range(1..=1000);
+                (n + b, '-', b)
+            }
+            '*' => {
+                let d = *self.rng.pick(&divisors);
+                (n / d, '*', d)
+            }
+            _ => {
+                let d = self.rng.range(2..=9);
+                (n * d, '/', d)
+            }
+        };
+
+        let left = self.rng.range(1..=size - 2);
+        let (a, b) = (self.number(a, left), self.number(b, size - 1 - left));
+        self.jobs.push(format!("{}: {} {} {}", name, a, op, b));
+        name
+    }
+}
+
+/// A random list of about `size` monkeys, at least a few. What `humn` yells is only ever added
+/// to, subtracted from, multiplied with or divided by other numbers, so that the second part is a
+/// linear equation, and the divisions are exact both for the number `humn` yells in the first part
+/// and for the solution of the second.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut troop = Troop {
+        rng,
+        names: HashSet::new(),
+        jobs: Vec::new(),
+    };
+
+    // the monkeys from `humn` up to `root`, each yelling `n` when `humn` yells the solution
+    let solution = troop.rng.range(1..=1_000_000);
+    let (mut n, mut name) = (solution, "humn".to_string());
+    // the product of the divisors on the way, multiples of which can be added to the solution
+    // with divisions staying exact, and that of the factors, which multiply such a difference
+    let (mut divisors, mut factors) = (1, 1);
+    let path = (size / 4).max(1);
+    let others = size / path / 2 + 1;
+    for _ in 0..path {
+        let (small, c) = (troop.rng.range(2..=9), troop.rng.range(1..=1000));
+        // the operation, what the other monkey yells, whether `humn` is on the left, and the
+        // result
+        let (op, other, left, next) = match troop.rng.range(0..=4) {
+            0 if n * small < LARGEST && factors * small < 10_000 => {
+                factors *= small;
+                ('*', small, troop.rng.chance(0.5), n * small)
+            }
+            1 if n % small == 0 && divisors * small < 1_000_000 => {
+                divisors *= small;
+                ('/', small, true, n / small)
+            }
+            2 if n > c => ('-', c, true, n - c),
+            3 => ('-', n + c, false, c),
+            _ => ('+', c, troop.rng.chance(0.5), n + c),
+        };
+        let other = troop.number(other, others);
+        let parent = troop.name();
+        let (a, b) = if left { (name, other) } else { (other, name) };
+        troop.jobs.push(format!("{}: {} {} {}", parent, a, op, b));
+        (n, name) = (next, parent);
+    }
+
+    let other = troop.number(n, size / 2);
+    let (a, b) = if troop.rng.chance(0.5) {
+        (name, other)
+    } else {
+        (other, name)
+    };
+    let op = *troop.rng.pick(&['+', '-']);
+    troop.jobs.push(format!("root: {} {} {}", a, op, b));
+    let humn = solution + troop.rng.range(1..=10) * divisors;
+    troop.jobs.push(format!("humn: {}", humn));
+
+    let mut jobs = troop.jobs;
+    rng.shuffle(&mut jobs);
+    jobs.join("\n")
+}
+
+pub struct Day21;
+
+impl crate::Solution for Day21 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Monkey Math"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input).map(Into::into))
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
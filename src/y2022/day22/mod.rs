@@ -0,0 +1,368 @@
+use std::str::FromStr;
+
+use crate::{
+    geom::{Dir, Point2, Turn},
+    grid::Grid,
+    parse::{self, Parser},
+    random::Rng,
+    visualize::Frames,
+};
+
+const DAY: u32 = 22;
+
+/// The value of facing `dir` in the final password.
+fn facing_value(dir: Dir) -> i32 {
+    match dir {
+        Dir::Right => 0,
+        Dir::Down => 1,
+        Dir::Left => 2,
+        Dir::Up => 3,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pos {
+    pos: Point2<i32>,
+    facing: Dir,
+}
+
+impl Pos {
+    fn mv(mut self) -> Self {
+        self.pos += self.facing.delta();
+        self
+    }
+}
+
+#[derive(Debug)]
+struct Map {
+    map: Grid<char>,
+    pos: Pos,
+    /// Each position moved to along with the facing, from the start on.
+    path: Vec<Pos>,
+}
+
+impl Map {
+    fn get(&self, pos: Point2<i32>) -> Option<char> {
+        self.map.get(pos.into()).copied()
+    }
+
+    fn mv(&mut self, n: i32) {
+        'outer: for _ in 0..n {
+            let mut cand = self.pos.mv();
+            loop {
+                match self.get(cand.pos) {
+                    Some('.') => break,
+                    Some('#') => break 'outer,
+                    Some(_) => cand = cand.mv(),
+                    None => match cand.facing {
+                        Dir::Right => cand.pos.x = 0,
+                        Dir::Down => cand.pos.y = 0,
+                        Dir::Left => cand.pos.x = self.map.width() as i32 - 1,
+                        Dir::Up => cand.pos.y = self.map.height() as i32 - 1,
+                    },
+                }
+            }
+            self.pos = cand;
+            self.path.push(cand);
+        }
+    }
+
+    fn mv_p2(&mut self, n: i32) {
+        'outer: for _ in 0..n {
+            let mut cand = self.pos.mv();
+            loop {
+                match self.get(cand.pos) {
+                    Some('.') => break,
+                    Some('#') => break 'outer,
+                    _ => {
+                        let Pos {
+                            pos: Point2 { x, y },
+                            facing,
+                        } = self.pos;
+                        match (x, y, facing) {
+                            (x, y, Dir::Left) if x < 50 && y < 150 => {
+                                cand = Pos {
+                                    pos: Point2::new(50, 49 - (y - 100)),
+                                    facing: Dir::Right,
+                                }
+                            }
+                            (x, y, Dir::Up) if x < 50 && y < 150 => {
+                                cand = Pos {
+                                    pos: Point2::new(50, 50 + x),
+                                    facing: Dir::Right,
+                                }
+                            }
+                            (x, y, Dir::Right) if x < 50 => {
+                                cand = Pos {
+                                    pos: Point2::new(50 + y - 150, 149),
+                                    facing: Dir::Up,
+                                }
+                            }
+                            (x, _, Dir::Down) if x < 50 => {
+                                cand = Pos {
+                                    pos: Point2::new(100 + x, 0),
+                                    facing: Dir::Down,
+                                }
+                            }
+                            (x, y, Dir::Left) if x < 50 => {
+                                cand = Pos {
+                                    pos: Point2::new(50 + y - 150, 0),
+                                    facing: Dir::Down,
+                                }
+                            }
+                            (x, y, Dir::Left) if x < 100 && y < 50 => {
+                                cand = Pos {
+                                    pos: Point2::new(0, 149 - y),
+                                    facing: Dir::Right,
+                                }
+                            }
+                            (x, y, Dir::Up) if x < 100 && y < 50 => {
+                                cand = Pos {
+                                    pos: Point2::new(0, 150 + x - 50),
+                                    facing: Dir::Right,
+                                }
+                            }
+                            (x, y, Dir::Right) if x < 100 && y < 100 => {
+                                cand = Pos {
+                                    pos: Point2::new(100 + y - 50, 49),
+                                    facing: Dir::Up,
+                                }
+                            }
+                            (x, y, Dir::Left) if x < 100 && y < 100 => {
+                                cand = Pos {
+                                    pos: Point2::new(y - 50, 100),
+                                    facing: Dir::Down,
+                                }
+                            }
+                            (x, y, Dir::Right) if x < 100 => {
+                                cand = Pos {
+                                    pos: Point2::new(149, 49 - (y - 100)),
+                                    facing: Dir::Left,
+                                }
+                            }
+                            (x, _, Dir::Down) if x < 100 => {
+                                cand = Pos {
+                                    pos: Point2::new(49, 150 + x - 50),
+                                    facing: Dir::Left,
+                                }
+                            }
+                            (_, y, Dir::Right) => {
+                                cand = Pos {
+                                    pos: Point2::new(99, 149 - y),
+                                    facing: Dir::Left,
+                                }
+                            }
+                            (x, _, Dir::Down) => {
+                                cand = Pos {
+                                    pos: Point2::new(99, 50 + x - 100),
+                                    facing: Dir::Left,
+                                }
+                            }
+                            (x, _, Dir::Up) => {
+                                cand = Pos {
+                                    pos: Point2::new(x - 100, 199),
+                                    facing: Dir::Up,
+                                }
+                            }
+                            _ => panic!("invalid pos"),
+                        }
+                    }
+                }
+            }
+            self.pos = cand;
+            self.path.push(cand);
+        }
+    }
+
+    /// The map with the path taken so far drawn as in the puzzle, by the facing at each tile, and
+    /// the current position as `@`.
+    fn draw(&self) -> Grid<char> {
+        let mut map = self.map.clone();
+        for pos in &self.path {
+            map[pos.pos.into()] = match pos.facing {
+                Dir::Right => '>',
+                Dir::Down => 'v',
+                Dir::Left => '<',
+                Dir::Up => '^',
+            };
+        }
+        map[self.pos.pos.into()] = '@';
+        map
+    }
+}
+
+impl FromStr for Map {
+    type Err = parse::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let p = Parser::new(DAY, s);
+        for line in s.lines() {
+            if let Some(idx) = line.find(|c| !matches!(c, ' ' | '.' | '#')) {
+                return Err(p.error(&line[idx..], "expected one of ' ', '.' or '#'"));
+            }
+        }
+
+        let map = Grid::from_rows(s.lines().map(|line| line.chars().collect()), ' ');
+        let pos = map
+            .position(|c| *c == '.')
+            .filter(|(_, y)| *y == 0)
+            .ok_or_else(|| p.error(s, "expected an open tile in the first row"))?;
+
+        let pos = Pos {
+            pos: pos.into(),
+            facing: Dir::Right,
+        };
+        Ok(Map {
+            map,
+            pos,
+            path: vec![pos],
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Instruction {
+    Go(i32),
+    Turn(Turn),
+}
+
+fn parse(s: &str) -> Result<(Map, Vec<Instruction>), parse::Error> {
+    let p = Parser::new(DAY, s);
+    let (map, path) = p.split_once(s, "\n\n")?;
+    let path = path.trim();
+
+    let mut insn = Vec::new();
+    let mut start = 0;
+    for (idx, c) in path.char_indices() {
+        if c.is_ascii_digit() {
+            continue;
+        }
+        if start < idx {
+            insn.push(Instruction::Go(p.num(&path[start..idx])?));
+        }
+        insn.push(Instruction::Turn(match c {
+            'L' => Turn::Left,
+            'R' => Turn::Right,
+            _ => return Err(p.error(&path[idx..], "expected a number, 'L' or 'R'")),
+        }));
+        start = idx + 1;
+    }
+    if start < path.len() {
+        insn.push(Instruction::Go(p.num(&path[start..])?));
+    }
+
+    Ok((p.parse(map)?, insn))
+}
+
+pub fn part1(input: &str, frames: &mut Frames) -> crate::Result<i32> {
+    let (mut map, insns) = parse(input)?;
+    for insn in insns {
+        match insn {
+            Instruction::Go(n) => {
+                map.mv(n);
+                frames.draw(|| map.draw());
+            }
+            Instruction::Turn(turn) => map.pos.facing = map.pos.facing.turn(turn),
+        }
+    }
+    let pass = (1 + map.pos.pos.y) * 1000 + (1 + map.pos.pos.x) * 4 + facing_value(map.pos.facing);
+    Ok(pass)
+}
+
+pub fn part2(input: &str, frames: &mut Frames) -> crate::Result<i32> {
+    let (mut map, insns) = parse(input)?;
+    for insn in insns {
+        match insn {
+            Instruction::Go(n) => {
+                map.mv_p2(n);
+                frames.draw(|| map.draw());
+            }
+            Instruction::Turn(turn) => map.pos.facing = map.pos.facing.turn(turn),
+        }
+    }
+    let pass = (1 + map.pos.pos.y) * 1000 + (1 + map.pos.pos.x) * 4 + facing_value(map.pos.facing);
+    Ok(pass)
+}
+
+/// A random map folding into a cube with faces 50 tiles wide, laid out as the second part expects,
+/// and a path of `size` moves with a turn between each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // the columns of faces in each band of 50 rows
+    let faces = [50..150, 50..100, 0..100, 0..50];
+    let mut rows = Vec::new();
+    for (band, columns) in faces.into_iter().enumerate() {
+        for y in band * 50..band * 50 + 50 {
+            let row: String = (0..columns.end)
+                .map(|x| match x {
+                    x if x < columns.start => ' ',
+                    50 if y == 0 => '.',
+                    _ if rng.chance(0.1) => '#',
+                    _ => '.',
+                })
+                .collect();
+            rows.push(row);
+        }
+    }
+
+    let mut path = rng.range(1..=50).to_string();
+    for _ in 1..size.max(1) {
+        path.push(*rng.pick(&['L', 'R']));
+        path.push_str(&rng.range(1..=50).to_string());
+    }
+    format!("{}\n\n{}", rows.join("\n"), path)
+}
+
+pub struct Day22;
+
+impl crate::Solution for Day22 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Monkey Map"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input, &mut Frames::off()).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input, &mut Frames::off()).map(Into::into))
+    }
+
+    fn visualize(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+        part: u32,
+        frames: &mut Frames,
+    ) -> Option<crate::Result<crate::Answer>> {
+        match part {
+            1 => Some(part1(input, frames).map(Into::into)),
+            2 => Some(part2(input, frames).map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    geom::{Bounds, Dir, Point2},
+    grid::Grid,
+    parse::{self, Parser},
+    random::Rng,
+    visualize::Frames,
+};
+
+const DAY: u32 = 23;
+
+type Point = Point2<i32>;
+
+/// The 3 positions an elf at `from` looks at before moving towards `dir`.
+fn look(from: Point, dir: Dir) -> impl Iterator<Item = Point> {
+    let ahead = from + dir.delta();
+    [ahead, ahead + dir.left().delta(), ahead + dir.right().delta()].into_iter()
+}
+
+#[derive(Debug)]
+struct Elf {
+    pos: Point,
+    proposal: Option<Point>,
+}
+
+impl Elf {
+    fn new(pos: Point) -> Self {
+        Elf {
+            pos,
+            proposal: None,
+        }
+    }
+
+    fn propose(&mut self, positions: &Positions, directions: &[Dir]) {
+        self.proposal = None;
+
+        if self.pos.neighbors8().all(|p| !positions.contains(&p)) {
+            return;
+        }
+
+        for dir in directions {
+            if look(self.pos, *dir).all(|p| !positions.contains(&p)) {
+                self.proposal = Some(self.pos + dir.delta());
+                return;
+            }
+        }
+    }
+
+    fn mv(&mut self, proposals: &HashMap<Point, i32>) -> bool {
+        if let Some(pos) = self.proposal {
+            if proposals[&pos] < 2 {
+                self.pos = pos;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+type Positions = HashSet<Point>;
+
+fn parse(s: &str) -> Result<(Vec<Elf>, Positions), parse::Error> {
+    let p = Parser::new(DAY, s);
+    let grid = Grid::parse(&p, s, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let elves: Vec<_> = grid
+        .iter()
+        .filter(|(_, elf)| **elf)
+        .map(|(pos, _)| Elf::new(pos.into()))
+        .collect();
+    let positions: Positions = elves.iter().map(|elf| elf.pos).collect();
+    if elves.is_empty() {
+        return Err(p.error(s, "expected an elf"));
+    }
+    Ok((elves, positions))
+}
+
+/// The elves as `#` on the smallest rectangle of ground containing them.
+fn draw(elves: &[Elf]) -> Grid<char> {
+    let Bounds { min, max } = Bounds::from_points(elves.iter().map(|elf| elf.pos)).unwrap();
+    let size = max - min + Point::new(1, 1);
+    let mut grid = Grid::new(size.x as usize, size.y as usize, '.');
+    for elf in elves {
+        grid[(elf.pos - min).into()] = '#';
+    }
+    grid
+}
+
+pub fn part1(input: &str, frames: &mut Frames) -> crate::Result<i32> {
+    let (mut elves, mut positions) = parse(input)?;
+    frames.draw(|| draw(&elves));
+    let mut directions = vec![Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    for _ in 0..10 {
+        for elf in &mut elves {
+            elf.propose(&positions, &directions);
+        }
+
+        let mut proposed = HashMap::new();
+        for pos in elves.iter().filter_map(|e| e.proposal) {
+            proposed.entry(pos).and_modify(|v| *v += 1).or_insert(1);
+        }
+
+        positions.clear();
+        for elf in &mut elves {
+            elf.mv(&proposed);
+            positions.insert(elf.pos);
+        }
+
+        directions.rotate_left(1);
+        frames.draw(|| draw(&elves));
+    }
+
+    let Bounds { min, max } =
+        Bounds::from_points(elves.iter().map(|elf| elf.pos)).expect("parsing rejects empty input");
+    let covered = (1 + max.x - min.x) * (1 + max.y - min.y) - elves.len() as i32;
+    Ok(covered)
+}
+
+pub fn part2(input: &str, frames: &mut Frames) -> crate::Result<i32> {
+    let (mut elves, mut positions) = parse(input)?;
+    frames.draw(|| draw(&elves));
+    let mut directions = vec![Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    for round in 1.. {
+        for elf in &mut elves {
+            elf.propose(&positions, &directions);
+        }
+
+        let mut proposed = HashMap::new();
+        for pos in elves.iter().filter_map(|e| e.proposal) {
+            proposed.entry(pos).and_modify(|v| *v += 1).or_insert(1);
+        }
+
+        positions.clear();
+        let mut moved = false;
+        for elf in &mut elves {
+            if elf.mv(&proposed) {
+                moved = true;
+            }
+            positions.insert(elf.pos);
+        }
+
+        if !moved {
+            return Ok(round);
+        }
+
+        directions.rotate_left(1);
+        frames.draw(|| draw(&elves));
+    }
+    unreachable!()
+}
+
+/// A random grove `size` tiles wide and high, about a third of which are elves.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(0.3) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    // there is at least one elf
+    rows[0].replace_range(..1, "#");
+    rows.join("\n")
+}
+
+pub struct Day23;
+
+impl crate::Solution for Day23 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Unstable Diffusion"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input, &mut Frames::off()).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input, &mut Frames::off()).map(Into::into))
+    }
+
+    fn visualize(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+        part: u32,
+        frames: &mut Frames,
+    ) -> Option<crate::Result<crate::Answer>> {
+        match part {
+            1 => Some(part1(input, frames).map(Into::into)),
+            2 => Some(part2(input, frames).map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    cycle::Cycle,
+    geom::{Dir, Point2},
+    grid::Grid,
+    parse::{self, Parser},
+    random::Rng,
+    search,
+    visualize::Frames,
+};
+
+const DAY: u32 = 24;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Tile {
+    Wall,
+    Space(Vec<Dir>),
+}
+
+type Point = Point2<i32>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Map(Grid<Tile>);
+
+impl Map {
+    fn height(&self) -> i32 {
+        self.0.height() as i32
+    }
+
+    fn width(&self) -> i32 {
+        self.0.width() as i32
+    }
+
+    fn put_blizzard(&mut self, p: Point, dir: Dir) {
+        if let Tile::Space(blizzards) = &mut self.0[p.into()] {
+            blizzards.push(dir);
+        } else {
+            panic!("can't put blizzard on wall tile");
+        }
+    }
+
+    fn put_wall(&mut self, p: Point) {
+        self.0[p.into()] = Tile::Wall;
+    }
+
+    fn get(&self, p: Point) -> &Tile {
+        &self.0[p.into()]
+    }
+
+    fn wrap(&self, p: Point, dir: Dir) -> Point {
+        match dir {
+            Dir::Right => Point::new(1, p.y),
+            Dir::Left => Point::new(self.width() - 2, p.y),
+            Dir::Down => Point::new(p.x, 1),
+            Dir::Up => Point::new(p.x, self.height() - 2),
+        }
+    }
+
+    fn neighbors(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.0.neighbors4(p.into()).map(Point::from)
+    }
+
+    fn tick(&self) -> Self {
+        let mut new = Map(Grid::new(
+            self.0.width(),
+            self.0.height(),
+            Tile::Space(Vec::new()),
+        ));
+
+        for (point, tile) in self.0.iter() {
+            let point = Point::from(point);
+            match tile {
+                Tile::Wall => new.put_wall(point),
+                Tile::Space(blizzards) => {
+                    for blizzard in blizzards {
+                        let mut cand = point + blizzard.delta();
+                        if let Tile::Wall = self.get(cand) {
+                            cand = self.wrap(cand, *blizzard);
+                        }
+                        new.put_blizzard(cand, *blizzard);
+                    }
+                }
+            }
+        }
+        new
+    }
+
+    /// The valley as drawn in the puzzle, with the number of blizzards where there are several,
+    /// and the expedition at `expedition` as `E`.
+    fn draw(&self, expedition: Point) -> Grid<char> {
+        let mut map = self.0.map(|tile| match tile {
+            Tile::Wall => '#',
+            Tile::Space(blizzards) => match blizzards[..] {
+                [] => '.',
+                [blizzard] => match blizzard {
+                    Dir::Up => '^',
+                    Dir::Right => '>',
+                    Dir::Down => 'v',
+                    Dir::Left => '<',
+                },
+                _ => char::from_digit(blizzards.len() as u32, 10).unwrap_or('*'),
+            },
+        });
+        map[expedition.into()] = 'E';
+        map
+    }
+}
+
+/// The positions of the expedition on a quickest way from `start` to `goal` when leaving at
+/// `minutes`, one for each minute until it reaches `goal`.
+fn travel(start: Point, goal: Point, minutes: usize, maps: &Cycle<Map>) -> Option<Vec<Point>> {
+    // the blizzards repeat, so states only differ by the minute within the cycle
+    search::a_star(
+        (maps.equivalent(minutes), start),
+        |&(minute, pos)| {
+            let minute = maps.equivalent(minute + 1);
+            let map = maps.at(minute);
+            map.neighbors(pos)
+                .chain([pos])
+                .filter(move |p| matches!(map.get(*p), Tile::Space(blizzards) if blizzards.is_empty()))
+                .map(move |p| ((minute, p), 1))
+        },
+        |(_, pos)| pos.manhattan(goal) as usize,
+        |(_, pos)| *pos == goal,
+    )
+    .path()
+    .map(|path| path.iter().map(|(_, pos)| *pos).collect())
+}
+
+/// Draws the expedition on its way along `path`, leaving at `minutes`.
+fn walk(maps: &Cycle<Map>, minutes: usize, path: &[Point], frames: &mut Frames) {
+    for (minute, pos) in (minutes..).zip(path) {
+        frames.draw(|| maps.at(minute).draw(*pos));
+    }
+}
+
+/// The blizzards at each minute, until they are back where they started.
+fn blizzards(map: Map) -> Cycle<Map> {
+    Cycle::find(map, |map| *map = map.tick(), Map::clone, Map::clone)
+}
+
+fn parse(input: &str) -> Result<(Map, Point, Point), parse::Error> {
+    let p = Parser::new(DAY, input);
+    let map = Grid::parse(&p, input, |c| match c {
+        '#' => Some(Tile::Wall),
+        '.' => Some(Tile::Space(Vec::new())),
+        '^' => Some(Tile::Space(vec![Dir::Up])),
+        '>' => Some(Tile::Space(vec![Dir::Right])),
+        'v' => Some(Tile::Space(vec![Dir::Down])),
+        '<' => Some(Tile::Space(vec![Dir::Left])),
+        _ => None,
+    })?;
+    if map.width() < 3 || map.height() < 3 {
+        return Err(p.error(input, "expected a valley surrounded by walls"));
+    }
+
+    let (start, goal) = (
+        Point::new(1, 0),
+        Point::new(map.width() as i32 - 2, map.height() as i32 - 1),
+    );
+
+    Ok((Map(map), start, goal))
+}
+
+fn no_path() -> crate::Error {
+    crate::Error::solve(DAY, "no path found")
+}
+
+pub fn part1(input: &str, frames: &mut Frames) -> crate::Result<usize> {
+    let (map, start, goal) = parse(input)?;
+    let maps = blizzards(map);
+    let path = travel(start, goal, 0, &maps).ok_or_else(no_path)?;
+    walk(&maps, 0, &path, frames);
+    Ok(path.len() - 1)
+}
+
+pub fn part2(input: &str, frames: &mut Frames) -> crate::Result<usize> {
+    let (map, start, goal) = parse(input)?;
+    let maps = blizzards(map);
+    let mut minutes = 0;
+    for (from, to) in [(start, goal), (goal, start), (start, goal)] {
+        let path = travel(from, to, minutes, &maps).ok_or_else(no_path)?;
+        walk(&maps, minutes, &path, frames);
+        minutes += path.len() - 1;
+    }
+    Ok(minutes)
+}
+
+/// A random valley `size` tiles wide and a quarter as high, about a third of its tiles holding
+/// a blizzard, through which there is a way back and forth. The height divides the width, so the
+/// blizzards repeat after as many minutes as the valley is wide.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let height = (size / 4).max(1);
+    let width = height * 4;
+    loop {
+        let mut rows = vec![format!("#.{}", "#".repeat(width))];
+        for _ in 0..height {
+            let row: String = (0..width)
+                .map(|x| {
+                    // blizzards in the columns of the entrance and exit would leave the valley
+                    let dirs: &[char] = if x == 0 || x == width - 1 {
+                        &['<', '>']
+                    } else {
+                        &['<', '>', '^', 'v']
+                    };
+                    if rng.chance(0.3) {
+                        *rng.pick(dirs)
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            rows.push(format!("#{}#", row));
+        }
+        rows.push(format!("{}.#", "#".repeat(width)));
+
+        let input = rows.join("\n");
+        if part2(&input, &mut Frames::off()).is_ok() {
+            return input;
+        }
+    }
+}
+
+pub struct Day24;
+
+impl crate::Solution for Day24 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Blizzard Basin"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input, &mut Frames::off()).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        Some(part2(input, &mut Frames::off()).map(Into::into))
+    }
+
+    fn visualize(
+        &self,
+        input: &str,
+        _: &crate::params::Overrides,
+        part: u32,
+        frames: &mut Frames,
+    ) -> Option<crate::Result<crate::Answer>> {
+        match part {
+            1 => Some(part1(input, frames).map(Into::into)),
+            2 => Some(part2(input, frames).map(Into::into)),
+            _ => None,
+        }
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
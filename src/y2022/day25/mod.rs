@@ -0,0 +1,115 @@
+use crate::{
+    parse::{self, Parser},
+    random::Rng,
+};
+
+const DAY: u32 = 25;
+
+fn to_decimal_digit(digit: char) -> Option<i64> {
+    match digit {
+        '=' => Some(-2),
+        '-' => Some(-1),
+        '0' => Some(0),
+        '1' => Some(1),
+        '2' => Some(2),
+        _ => None,
+    }
+}
+
+fn to_decimal(p: &Parser, snafu: &str) -> Result<i64, parse::Error> {
+    let mut res = 0;
+    for (idx, c) in snafu.char_indices() {
+        let digit = to_decimal_digit(c)
+            .ok_or_else(|| p.error(&snafu[idx..], "expected one of '=', '-', '0', '1' or '2'"))?;
+        res = 5 * res + digit;
+    }
+    Ok(res)
+}
+
+fn parse(input: &str) -> Result<Vec<i64>, parse::Error> {
+    let p = Parser::new(DAY, input);
+    input.lines().map(|line| to_decimal(&p, line)).collect()
+}
+
+fn to_snafu_digit(digit: i64) -> char {
+    match digit {
+        -2 => '=',
+        -1 => '-',
+        0 => '0',
+        1 => '1',
+        2 => '2',
+        _ => panic!("invalid digit"),
+    }
+}
+
+pub fn part1(input: &str) -> crate::Result<String> {
+    let mut sum: i64 = parse(input)?.into_iter().sum();
+
+    let mut snafu = Vec::new();
+    while sum > 0 {
+        let mut digit = sum.rem_euclid(5);
+        sum /= 5;
+
+        if digit > 2 {
+            digit -= 5;
+            sum += 1;
+        }
+
+        snafu.push(to_snafu_digit(digit));
+    }
+    Ok(snafu.into_iter().rev().collect())
+}
+
+/// `size` random SNAFU numbers of up to 20 digits, as large as the puzzle's.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let numbers: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.range(0..=19);
+            let mut snafu = rng.pick(&['1', '2']).to_string();
+            snafu.extend((0..digits).map(|_| to_snafu_digit(rng.range(-2..=2))));
+            snafu
+        })
+        .collect();
+    numbers.join("\n")
+}
+
+pub struct Day25;
+
+impl crate::Solution for Day25 {
+    fn year(&self) -> u32 {
+        super::YEAR
+    }
+
+    fn day(&self) -> u32 {
+        DAY
+    }
+
+    fn name(&self) -> &'static str {
+        "Full of Hot Air"
+    }
+
+    fn parse(&self, input: &str) -> Option<crate::Result<()>> {
+        crate::solution::parsed(parse(input))
+    }
+
+    fn part1(&self, input: &str, _: &crate::params::Overrides) -> crate::Result<crate::Answer> {
+        part1(input).map(Into::into)
+    }
+
+    fn part2(
+        &self,
+        _: &str,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<crate::Answer>> {
+        None
+    }
+
+    fn generate(
+        &self,
+        rng: &mut crate::random::Rng,
+        size: usize,
+        _: &crate::params::Overrides,
+    ) -> Option<crate::Result<String>> {
+        Some(Ok(generate(rng, size)))
+    }
+}
@@ -0,0 +1,21 @@
+use aoc::{solution, Solution};
+
+#[test]
+fn registry() {
+    assert_eq!(solution::all(2022).len(), 25);
+
+    let day17 = solution::get(2022, 17).unwrap();
+    assert_eq!((day17.year(), day17.day()), (2022, 17));
+    assert_eq!(day17.name(), aoc::y2022::day17::Day17.name());
+    assert!(solution::get(2022, 26).is_none());
+    assert!(solution::get(2021, 1).is_none());
+    assert!(solution::all(2021).is_empty());
+}
+
+#[test]
+fn parse_error() {
+    let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n\
+                 Sensor at x=9, y=16: closest beacon is at x=10, y=x";
+    let err = aoc::y2022::day15::part1(input, &Default::default()).unwrap_err();
+    assert_eq!(err.to_string(), "day15: line 2, col 51: expected number");
+}